@@ -0,0 +1,113 @@
+//! Benchmarks of reading cells from grids, by index, by row and by column.
+//!
+//! Run with `cargo bench --bench grid`, add `-- --save-baseline <name>` to keep the numbers, and
+//! `-- --baseline <name>` to compare against them later.
+use std::hint::black_box;
+
+use advent_of_code_2023::shared::grids::GridIter as _;
+use advent_of_code_2023::shared::grids::grid::Grid;
+use advent_of_code_2023::shared::grids::infinite_grid::InfiniteGrid;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// As wide and as tall as most puzzle inputs.
+const SIZE: usize = 140;
+
+/// A square map of rocks and gardens, like the input of day 21.
+fn input() -> String {
+    (0..SIZE)
+        .map(|row_index| {
+            (0..SIZE)
+                .map(|column_index| {
+                    if (row_index * 7 + column_index * 3) % 5 == 0 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn grid(c: &mut Criterion) {
+    let grid = Grid::<char>::parse(&input()).unwrap();
+
+    let mut group = c.benchmark_group("grid");
+
+    group.bench_function("index", |b| {
+        b.iter(|| {
+            let grid = black_box(&grid);
+
+            (0..SIZE)
+                .flat_map(|row_index| (0..SIZE).map(move |column_index| (row_index, column_index)))
+                .filter(|&index| grid[index] == '#')
+                .count()
+        });
+    });
+
+    group.bench_function("rows", |b| {
+        b.iter(|| {
+            black_box(&grid)
+                .rows()
+                .map(|row| row.iter().filter(|&&cell| cell == '#').count())
+                .sum::<usize>()
+        });
+    });
+
+    group.bench_function("columns", |b| {
+        b.iter(|| {
+            black_box(&grid)
+                .column_iter()
+                .map(|column| column.into_iter().filter(|&&cell| cell == '#').count())
+                .sum::<usize>()
+        });
+    });
+
+    group.finish();
+}
+
+fn infinite_grid(c: &mut Criterion) {
+    let grid = InfiniteGrid::<char>::parse(&input()).unwrap();
+
+    let size = isize::try_from(SIZE).unwrap();
+
+    let mut group = c.benchmark_group("infinite_grid");
+
+    // one copy of the map on either side of the original, to include wrapping around
+    group.bench_function("index", |b| {
+        b.iter(|| {
+            let grid = black_box(&grid);
+
+            (-size..2 * size)
+                .flat_map(|row_index| {
+                    (-size..2 * size).map(move |column_index| (row_index, column_index))
+                })
+                .filter(|&index| grid[index] == '#')
+                .count()
+        });
+    });
+
+    group.bench_function("rows", |b| {
+        b.iter(|| {
+            black_box(&grid)
+                .rows()
+                .map(|row| row.iter().filter(|&&cell| cell == '#').count())
+                .sum::<usize>()
+        });
+    });
+
+    group.bench_function("columns", |b| {
+        b.iter(|| {
+            black_box(&grid)
+                .column_iter()
+                .map(|column| column.into_iter().filter(|&&cell| cell == '#').count())
+                .sum::<usize>()
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, grid, infinite_grid);
+criterion_main!(benches);
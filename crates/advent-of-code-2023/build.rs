@@ -0,0 +1,64 @@
+//! Tells the tests which of our inputs we have.
+//!
+//! Every `YYYY/inputs/DD.txt` found in the data folders sets `has_input_YYYY_DD`, which the tests of
+//! our answers use to be ignored, rather than fail, when we don't have the input they solve. An
+//! empty `DD.txt`, as `aoc scaffold` leaves it, is no input.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The event the solutions in this crate are for, like `days::YEAR`.
+const YEAR: u16 = 2023;
+
+/// Like `solution::DATA_DIR_ENV`.
+const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Watches `path` for changes, or the closest folder it would appear in when it doesn't exist yet.
+fn watch(path: &Path) {
+    if let Some(existing) = path.ancestors().find(|ancestor| ancestor.exists()) {
+        println!("cargo::rerun-if-changed={}", existing.display());
+    }
+}
+
+/// Whether `path` is a file with something in it.
+fn has_input(path: &Path) -> bool {
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0)
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed={}", DATA_DIR_ENV);
+
+    // the data folders of `solution::data_roots`, but the one of the working directory, which is
+    // this crate's when its tests run
+    let roots = [
+        env::var_os(DATA_DIR_ENV)
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from),
+        env::var_os("CARGO_MANIFEST_DIR").map(|dir| PathBuf::from(dir).join("data")),
+    ];
+
+    let folders = roots
+        .into_iter()
+        .flatten()
+        .map(|root| root.join(YEAR.to_string()).join("inputs"))
+        .collect::<Vec<_>>();
+
+    for folder in &folders {
+        watch(folder);
+    }
+
+    for day in 1..=25 {
+        let cfg = format!("has_input_{}_{:02}", YEAR, day);
+
+        println!("cargo::rustc-check-cfg=cfg({})", cfg);
+
+        if folders
+            .iter()
+            .any(|folder| has_input(&folder.join(format!("{:02}.txt", day))))
+        {
+            println!("cargo::rustc-cfg={}", cfg);
+        }
+    }
+}
@@ -0,0 +1,726 @@
+use std::env;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use advent_of_code_2023::days::{self, YEAR};
+use advent_of_code_2023::shared::baseline::{Baseline, baselines_dir, current_revision};
+use advent_of_code_2023::shared::day::Day;
+use advent_of_code_2023::shared::error::SolveError;
+use advent_of_code_2023::shared::fetch::{Fetched, Fetcher};
+use advent_of_code_2023::shared::input::InputSource;
+use advent_of_code_2023::shared::puzzle::PuzzleId;
+use advent_of_code_2023::shared::results::{PartResult, PuzzleResult, Results, Timings};
+use advent_of_code_2023::shared::scaffold::{Template, scaffold};
+use advent_of_code_2023::shared::solution::{Normalized, normalize};
+use advent_of_code_2023::shared::submit::{Submitter, Verdict};
+use advent_of_code_2023::shared::timing::{Stats, time};
+use advent_of_code_2023::shared::{PartSolution, Parts};
+
+const USAGE: &str = "\
+Usage: aoc [OPTIONS] [PUZZLE]...
+       aoc scaffold [--template <TEMPLATE>] <PUZZLE>
+
+Runs the solutions of the given puzzles, or of every registered puzzle when none are given.
+
+`scaffold` creates the solution of a new puzzle from a template, registers it, and creates empty
+example and input files. It never overwrites an existing solution. The templates are `blank` (the
+default), `grid`, `graph` and `sections`.
+
+Arguments:
+  [PUZZLE]...  Puzzles like `2023-17`, day numbers of the default year, or `all`
+
+Options:
+  --input <PATH>         Reads the input of the single given puzzle from PATH, or from stdin for `-`
+  --profile <NAME>       Runs on the inputs in `<YEAR>/inputs/<NAME>` instead, can be repeated
+  --fetch                Downloads the inputs we don't have yet before running
+  --submit               Submits the answers of parts whose answer we don't know yet
+  --bench <N>            Runs every part N times, and reports min, median, mean and standard deviation
+  --save                 Saves the timings as the baseline of the current git revision
+  --compare <REVISION>   Fails on parts that got slower than the baseline saved at REVISION
+  --threshold <PERCENT>  How much slower a part may get before --compare fails it [default: 10]
+  --json <PATH>          Writes the answers, verdicts and timings of every part to PATH as JSON
+  --junit <PATH>         Writes them to PATH as JUnit XML, with a test case per part
+  -h, --help             Prints this help
+
+The answers of a profile are read from `<YEAR>/inputs/<NAME>/answers.toml` when it exists, other
+answers are only printed. --fetch, --submit, --save and --compare only work on the checked-in inputs.
+
+Baselines are stored in `baselines/<REVISION>/<PUZZLE>-<PART>.json` in the data folder.
+
+--fetch and --submit read the session cookie from AOC_SESSION, or from ~/.config/aoc/session, and
+the server from AOC_BASE_URL [default: https://adventofcode.com]. Submitted answers are kept in
+`<YEAR>/submissions/<DAY>-<PART>.json` in the data folder, and answers known to be wrong are never
+submitted again.";
+
+struct Options {
+    puzzles: Vec<PuzzleId>,
+    sources: Vec<InputSource>,
+    fetch: bool,
+    submit: bool,
+    bench: Option<NonZeroU32>,
+    save: bool,
+    compare: Option<String>,
+    threshold: f64,
+    json: Option<PathBuf>,
+    junit: Option<PathBuf>,
+}
+
+/// How the timings of a part are checked against, and stored as, baselines.
+struct Baselines {
+    dir: PathBuf,
+    save: Option<String>,
+    compare: Option<String>,
+    threshold: f64,
+}
+
+/// Parses a puzzle like `2023-17`, or a day number of [`YEAR`].
+fn parse_puzzle(arg: &str) -> Result<PuzzleId, String> {
+    if arg.contains('-') {
+        return arg.parse().map_err(|error| format!("{}", error));
+    }
+
+    let day = arg.parse::<Day>().map_err(|error| format!("{}", error))?;
+
+    PuzzleId::try_new(YEAR, day).ok_or_else(|| format!("{} has no puzzle on day {}", YEAR, day))
+}
+
+/// Parses the arguments. An empty list of puzzles, or `all`, selects every registered puzzle.
+fn parse_args(args: &[String], registered: &[PuzzleId]) -> Result<Options, String> {
+    let mut puzzles = vec![];
+    let mut sources = vec![];
+    let mut all = args.is_empty();
+    let mut fetch = false;
+    let mut submit = false;
+    let mut bench = None;
+    let mut save = false;
+    let mut compare = None;
+    let mut threshold = 10.0;
+    let mut json = None;
+    let mut junit = None;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => {
+                let runs = args.next().ok_or("--bench expects a number of runs")?;
+
+                bench = Some(
+                    runs.parse()
+                        .map_err(|_| format!("invalid number of runs `{}`", runs))?,
+                );
+            },
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or("--input expects a path, or `-` for stdin")?;
+
+                sources.push(InputSource::from_arg(path));
+            },
+            "--profile" => {
+                let name = args.next().ok_or("--profile expects a name")?;
+
+                sources.push(
+                    InputSource::profile(name)
+                        .ok_or_else(|| format!("invalid profile `{}`", name))?,
+                );
+            },
+            "--fetch" => fetch = true,
+            "--submit" => submit = true,
+            "--save" => save = true,
+            "--compare" => {
+                compare = Some(args.next().ok_or("--compare expects a revision")?.clone());
+            },
+            "--threshold" => {
+                let percent = args.next().ok_or("--threshold expects a percentage")?;
+
+                threshold = percent
+                    .parse()
+                    .ok()
+                    .filter(|p: &f64| p.is_finite() && *p >= 0.0)
+                    .ok_or_else(|| format!("invalid threshold `{}`", percent))?;
+            },
+            "--json" => json = Some(PathBuf::from(args.next().ok_or("--json expects a path")?)),
+            "--junit" => {
+                junit = Some(PathBuf::from(args.next().ok_or("--junit expects a path")?));
+            },
+            "all" => all = true,
+            puzzle => puzzles.push(parse_puzzle(puzzle)?),
+        }
+    }
+
+    if all || puzzles.is_empty() {
+        puzzles = registered.to_vec();
+    }
+
+    puzzles.sort_unstable();
+    puzzles.dedup();
+
+    if sources.iter().any(|source| !source.is_per_puzzle()) && (all || puzzles.len() != 1) {
+        return Err(String::from("--input needs a single puzzle"));
+    }
+
+    if sources
+        .iter()
+        .filter(|&source| *source == InputSource::Stdin)
+        .count()
+        > 1
+    {
+        return Err(String::from("stdin can only be read once"));
+    }
+
+    if !sources.is_empty() && (fetch || submit || save || compare.is_some()) {
+        return Err(String::from(
+            "--fetch, --submit, --save and --compare only work on the checked-in inputs",
+        ));
+    }
+
+    if sources.is_empty() {
+        sources.push(InputSource::CheckedIn);
+    }
+
+    Ok(Options {
+        puzzles,
+        sources,
+        fetch,
+        submit,
+        bench,
+        save,
+        compare,
+        threshold,
+        json,
+        junit,
+    })
+}
+
+/// Downloads the inputs of `puzzles` we don't have yet.
+///
+/// Returns `false` when any of them couldn't be fetched.
+fn fetch_inputs(puzzles: &[PuzzleId]) -> bool {
+    let fetcher = match Fetcher::from_env() {
+        Ok(fetcher) => fetcher,
+        Err(error) => {
+            eprintln!("{}", error);
+
+            return false;
+        },
+    };
+
+    let mut fetched_all = true;
+
+    for &puzzle in puzzles {
+        match fetcher.fetch_input(puzzle) {
+            Ok(Fetched::Downloaded(path)) => {
+                println!("{}: downloaded to {}", puzzle, path.display());
+            },
+            Ok(Fetched::Cached(_)) => {},
+            Err(error) => {
+                eprintln!("{}: {}", puzzle, error);
+
+                fetched_all = false;
+            },
+        }
+    }
+
+    fetched_all
+}
+
+/// The timings of a single puzzle.
+struct Report {
+    puzzle: PuzzleId,
+    source: InputSource,
+    read: Duration,
+    parse: Stats,
+    parts: [Option<Stats>; 2],
+}
+
+/// Runs a step `runs` times, returning the first result and the statistics over all runs.
+fn run_part<T>(
+    solve: impl Fn() -> Result<T, SolveError>,
+    runs: NonZeroU32,
+) -> Result<(T, Stats), SolveError> {
+    let (answer, first) = time(&solve);
+    let answer = answer?;
+
+    let mut samples = vec![first];
+
+    for _ in 1..runs.get() {
+        let (_, elapsed) = time(&solve);
+
+        samples.push(elapsed);
+    }
+
+    let stats = Stats::from_samples(&samples).expect("We have at least 1 sample");
+
+    Ok((answer, stats))
+}
+
+/// Saves the timings of a part, and compares them with an earlier baseline.
+///
+/// Returns `false` when the part got slower than the threshold allows, or a baseline fails to load
+/// or save.
+fn check_baseline(
+    baselines: &Baselines,
+    puzzle: PuzzleId,
+    part: u8,
+    runs: u32,
+    stats: &Stats,
+) -> bool {
+    let mut passed = true;
+
+    if let Some(ref revision) = baselines.compare {
+        match Baseline::load(&baselines.dir, revision, puzzle, part) {
+            Ok(Some(baseline)) => {
+                if let Some(slowdown) = baseline.regression(stats.median, baselines.threshold) {
+                    println!(
+                        "    SLOWER than {:.2?} at {} (+{:.1}%)",
+                        baseline.median(),
+                        revision,
+                        slowdown
+                    );
+
+                    passed = false;
+                } else {
+                    println!("    baseline {:.2?} at {}", baseline.median(), revision);
+                }
+            },
+            Ok(None) => println!("    no baseline at {}", revision),
+            Err(error) => {
+                eprintln!("{}, part {}: {}", puzzle, part, error);
+
+                passed = false;
+            },
+        }
+    }
+
+    if let Some(ref revision) = baselines.save {
+        if let Err(error) = Baseline::new(puzzle, part, revision, runs, stats).save(&baselines.dir)
+        {
+            eprintln!("{}, part {}: {}", puzzle, part, error);
+
+            passed = false;
+        }
+    }
+
+    passed
+}
+
+/// How the output refers to a puzzle run on the input from `source`.
+fn label(puzzle: PuzzleId, source: &InputSource) -> String {
+    if *source == InputSource::CheckedIn {
+        puzzle.to_string()
+    } else {
+        format!("{} ({})", puzzle, source)
+    }
+}
+
+/// Runs both parts of a puzzle on the input from `source`, and verifies them against the expected
+/// answers.
+///
+/// Returns whether every part solves and matches its known answer, together with the timings and
+/// the results of every part. A puzzle we have no input for is skipped, which doesn't fail the run.
+fn run_day(
+    puzzle: PuzzleId,
+    solution: &dyn Parts,
+    source: &InputSource,
+    expected: [PartSolution; 2],
+    bench: Option<NonZeroU32>,
+    baselines: &Baselines,
+    submitter: Option<&Submitter>,
+) -> (bool, Option<Report>, PuzzleResult) {
+    let (input, read) = time(|| source.read(puzzle).map(normalize));
+
+    let label = label(puzzle, source);
+    let mut puzzle_result = PuzzleResult::new(puzzle, source, read);
+
+    let Normalized { input, diagnostics } = match input {
+        Ok(normalized) => normalized,
+        Err(error) if error.is_missing() => {
+            println!("{}: skipped, no input available", label);
+
+            return (true, None, puzzle_result.skipped("no input available"));
+        },
+        Err(error) => {
+            eprintln!("{}: {}", label, error);
+
+            return (false, None, puzzle_result.with_error(&error.to_string()));
+        },
+    };
+
+    for diagnostic in diagnostics {
+        let kind = if diagnostic.is_fixed() {
+            "fixed"
+        } else {
+            "warning"
+        };
+
+        eprintln!("{}: input: {}: {}", label, kind, diagnostic);
+    }
+
+    let runs = bench.unwrap_or(NonZeroU32::MIN);
+
+    let (prepared, parse) = match run_part(|| solution.prepare(&input), runs) {
+        Ok(prepared) => prepared,
+        Err(error) => {
+            eprintln!("{}: {}", label, error);
+
+            return (false, None, puzzle_result.with_error(&error.to_string()));
+        },
+    };
+
+    puzzle_result.parse = Some(Timings::new(runs.get(), &parse));
+
+    if bench.is_some() {
+        println!(
+            "{}, parse: min {:.2?}, median {:.2?}, mean {:.2?}, std dev {:.2?}",
+            label, parse.min, parse.median, parse.mean, parse.std_dev
+        );
+    }
+
+    let results = [
+        run_part(|| prepared.try_part_1(), runs),
+        run_part(|| prepared.try_part_2(), runs),
+    ];
+
+    let mut all_correct = true;
+    let mut parts = [None, None];
+
+    for (((part, expected), result), stats) in (1_u8..).zip(expected).zip(results).zip(&mut parts) {
+        let (actual, part_stats) = match result {
+            Ok(result) => result,
+            Err(error) => {
+                eprintln!("{}, part {}: {}", label, part, error);
+
+                puzzle_result
+                    .parts
+                    .push(PartResult::error(part, &error.to_string()));
+
+                all_correct = false;
+
+                continue;
+            },
+        };
+
+        *stats = Some(part_stats);
+
+        puzzle_result.parts.push(PartResult::solved(
+            part,
+            &actual,
+            &expected,
+            Timings::new(runs.get(), &part_stats),
+        ));
+
+        let timing = format!("[{:.2?}]", part_stats.median);
+
+        if !expected.has_solution() {
+            println!("{}, part {}: {} {}", label, part, actual, timing);
+
+            if let Some(submitter) = submitter {
+                all_correct &= submit_answer(submitter, puzzle, part, &actual);
+            }
+        } else if matches!(expected, PartSolution::Manual(_)) {
+            println!("{}, part {}: {} (manual) {}", label, part, actual, timing);
+        } else if expected == actual {
+            println!("{}, part {}: {} (ok) {}", label, part, actual, timing);
+        } else {
+            println!(
+                "{}, part {}: {} (FAILED, expected {}) {}",
+                label, part, actual, expected, timing
+            );
+
+            all_correct = false;
+        }
+
+        if bench.is_some() {
+            println!(
+                "    min {:.2?}, median {:.2?}, mean {:.2?}, std dev {:.2?}",
+                part_stats.min, part_stats.median, part_stats.mean, part_stats.std_dev
+            );
+        }
+
+        all_correct &= check_baseline(baselines, puzzle, part, runs.get(), &part_stats);
+    }
+
+    (
+        all_correct,
+        Some(Report {
+            puzzle,
+            source: source.clone(),
+            read,
+            parse,
+            parts,
+        }),
+        puzzle_result,
+    )
+}
+
+/// Submits the answer of a part we don't know the answer of yet.
+///
+/// Returns `false` when the answer isn't correct, or couldn't be submitted.
+fn submit_answer(submitter: &Submitter, puzzle: PuzzleId, part: u8, answer: &PartSolution) -> bool {
+    match submitter.submit(puzzle, part, answer) {
+        Ok(Verdict::Correct) => {
+            println!(
+                "    submitted, correct! Add it to the answers of {} in answers.toml",
+                puzzle
+            );
+
+            true
+        },
+        Ok(verdict) => {
+            println!("    submitted, {}", verdict);
+
+            false
+        },
+        Err(error) => {
+            eprintln!("{}, part {}: {}", puzzle, part, error);
+
+            false
+        },
+    }
+}
+
+/// Scaffolds the puzzle in `args`, in the crate this binary was built from.
+fn scaffold_puzzle(args: &[String]) -> ExitCode {
+    let parsed = match *args {
+        [ref puzzle] => parse_puzzle(puzzle).map(|puzzle| (puzzle, Template::default())),
+        [ref flag, ref template, ref puzzle] if flag == "--template" => template
+            .parse()
+            .map_err(|error| format!("{}", error))
+            .and_then(|template| Ok((parse_puzzle(puzzle)?, template))),
+        _ => Err(String::from("scaffold expects a single puzzle")),
+    };
+
+    let scaffolded = parsed.and_then(|(puzzle, template)| {
+        scaffold(Path::new(env!("CARGO_MANIFEST_DIR")), puzzle, template)
+            .map(|scaffolded| (puzzle, scaffolded))
+            .map_err(|error| format!("{}", error))
+    });
+
+    match scaffolded {
+        Ok((puzzle, scaffolded)) => {
+            for path in scaffolded.created {
+                println!("created {}", path.display());
+            }
+
+            for path in scaffolded.updated {
+                println!("updated {}", path.display());
+            }
+
+            println!(
+                "\nRun `aoc --fetch {}` to download the input of {}",
+                puzzle, puzzle
+            );
+
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Formats the median of a part, or a dash when it didn't solve.
+fn format_median(stats: Option<Stats>) -> String {
+    stats.map_or_else(|| String::from("-"), |s| format!("{:.2?}", s.median))
+}
+
+fn print_summary(reports: &[Report]) {
+    let labels = reports
+        .iter()
+        .map(|report| label(report.puzzle, &report.source))
+        .collect::<Vec<_>>();
+
+    let width = labels.iter().map(String::len).max().unwrap_or(0).max(8);
+
+    println!();
+    println!(
+        "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "Puzzle", "Read", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    let mut grand_total = Duration::ZERO;
+
+    for (report, label) in reports.iter().zip(labels) {
+        let total = report.read
+            + report.parse.median
+            + report
+                .parts
+                .iter()
+                .flatten()
+                .map(|stats| stats.median)
+                .sum::<Duration>();
+
+        grand_total += total;
+
+        println!(
+            "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            label,
+            format!("{:.2?}", report.read),
+            format!("{:.2?}", report.parse.median),
+            format_median(report.parts[0]),
+            format_median(report.parts[1]),
+            format!("{:.2?}", total)
+        );
+    }
+
+    println!(
+        "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "All",
+        "",
+        "",
+        "",
+        "",
+        format!("{:.2?}", grand_total)
+    );
+}
+
+/// Writes the results to the files given with `--json` and `--junit`.
+///
+/// Returns `false` when any of them couldn't be written.
+fn write_results(results: &Results, json: Option<&Path>, junit: Option<&Path>) -> bool {
+    let mut written = true;
+
+    if let Some(path) = json
+        && let Err(error) = results.write_json(path)
+    {
+        eprintln!("{}: {}", path.display(), error);
+
+        written = false;
+    }
+
+    if let Some(path) = junit
+        && let Err(error) = results.write_junit(path)
+    {
+        eprintln!("{}: {}", path.display(), error);
+
+        written = false;
+    }
+
+    written
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some((command, rest)) = args.split_first()
+        && command == "scaffold"
+    {
+        return scaffold_puzzle(rest);
+    }
+
+    let registered = days::puzzles();
+
+    let options = match parse_args(&args, &registered) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let save = if options.save {
+        let Some(revision) = current_revision() else {
+            eprintln!("--save needs the current git revision, which couldn't be determined");
+
+            return ExitCode::FAILURE;
+        };
+
+        Some(revision)
+    } else {
+        None
+    };
+
+    let baselines = Baselines {
+        dir: baselines_dir(),
+        save,
+        compare: options.compare,
+        threshold: options.threshold,
+    };
+
+    let submitter = if options.submit {
+        match Submitter::from_env() {
+            Ok(submitter) => Some(submitter),
+            Err(error) => {
+                eprintln!("{}", error);
+
+                return ExitCode::FAILURE;
+            },
+        }
+    } else {
+        None
+    };
+
+    let mut all_correct = true;
+    let mut reports = vec![];
+
+    let mut results = Results {
+        revision: current_revision(),
+        timestamp: SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs()),
+        puzzles: vec![],
+    };
+
+    if options.fetch {
+        all_correct &= fetch_inputs(&options.puzzles);
+    }
+
+    for source in &options.sources {
+        let mut answers = match source.answers(YEAR) {
+            Ok(answers) => answers,
+            Err(error) => {
+                eprintln!("{}: {}", source, error);
+
+                all_correct = false;
+
+                continue;
+            },
+        };
+
+        for &puzzle in &options.puzzles {
+            let Some(solution) = days::get(puzzle) else {
+                eprintln!("{}: no solution registered", puzzle);
+
+                all_correct = false;
+
+                continue;
+            };
+
+            let (correct, report, result) = run_day(
+                puzzle,
+                solution.as_ref(),
+                source,
+                answers.take(puzzle.day()),
+                options.bench,
+                &baselines,
+                submitter.as_ref(),
+            );
+
+            all_correct &= correct;
+            reports.extend(report);
+            results.puzzles.push(result);
+        }
+    }
+
+    print_summary(&reports);
+
+    all_correct &= write_results(&results, options.json.as_deref(), options.junit.as_deref());
+
+    if all_correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
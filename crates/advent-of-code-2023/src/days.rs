@@ -1 +1,58 @@
+use crate::shared::Parts;
+use crate::shared::puzzle::PuzzleId;
+use crate::shared::year::Year;
 
+/// The event the solutions in this crate are for.
+pub const YEAR: Year = crate::year!(2023);
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        /// All puzzles we have a solution for, ordered by year and day.
+        #[must_use]
+        pub fn puzzles() -> Vec<PuzzleId> {
+            vec![$(*$module::PUZZLE),*]
+        }
+
+        /// The solution of a single puzzle, if we have solved it.
+        ///
+        /// Only the solution of `puzzle` is created.
+        #[must_use]
+        pub fn get(puzzle: PuzzleId) -> Option<Box<dyn Parts>> {
+            match puzzle {
+                $(puzzle if puzzle == *$module::PUZZLE => {
+                    Some(Box::new($module::Solution::default()))
+                },)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23,
+);
@@ -1,8 +1,8 @@
 #![expect(clippy::string_slice, reason = "Advent of Code is ASCII only")]
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::{PartSolution, Parts};
 
-advent_of_code_2023::solution!(54159, 53866);
+crate::solution!();
 
 fn first_09_digit(line: &str) -> Option<(usize, u32)> {
     line.chars()
@@ -17,7 +17,12 @@ fn last_09_digit(line: &str) -> Option<(usize, u32)> {
         .find_map(|(index, c)| c.to_digit(10).map(|d| (line.len() - index - 1, d)))
 }
 
-fn calculate_total_calibration_value_part_1(lines: &str) -> u32 {
+/// Sums the calibration values, only counting the digits `0` through `9`.
+///
+/// # Panics
+/// When a line contains no digit.
+#[must_use]
+pub fn calculate_total_calibration_value_part_1(lines: &str) -> u32 {
     let mut total = 0;
     for line in lines.lines() {
         let (_, first_number) = first_09_digit(line).expect("No number found");
@@ -52,7 +57,12 @@ fn get_first_last(
     }
 }
 
-fn calculate_total_calibration_value_part_2(lines: &str) -> u32 {
+/// Sums the calibration values, where spelled out numbers like `two` also count as digits.
+///
+/// # Panics
+/// When a line contains neither a digit nor a spelled out number.
+#[must_use]
+pub fn calculate_total_calibration_value_part_2(lines: &str) -> u32 {
     let mut total = 0;
     for line in lines.lines() {
         let first_09_digit = first_09_digit(line);
@@ -73,14 +83,12 @@ fn calculate_total_calibration_value_part_2(lines: &str) -> u32 {
     total
 }
 
-fn first_word_digit(line: &str, first_09_digit_index: usize) -> Option<(usize, u32)> {
+#[must_use]
+pub fn first_word_digit(line: &str, first_09_digit_index: usize) -> Option<(usize, u32)> {
     for i in 0..first_09_digit_index {
-        for (word_index, word) in NUMBER_WORDS.iter().enumerate() {
+        for (number, word) in (1..).zip(NUMBER_WORDS) {
             if line[i..].starts_with(word) {
-                return Some((
-                    i,
-                    u32::try_from(word_index).expect("index doesn't fit u32") + 1,
-                ));
+                return Some((i, number));
             }
         }
     }
@@ -88,14 +96,12 @@ fn first_word_digit(line: &str, first_09_digit_index: usize) -> Option<(usize, u
     None
 }
 
-fn last_word_digit(line: &str, last_09_digit_index: usize) -> Option<(usize, u32)> {
+#[must_use]
+pub fn last_word_digit(line: &str, last_09_digit_index: usize) -> Option<(usize, u32)> {
     for i in (last_09_digit_index..line.len()).rev() {
-        for (word_index, word) in NUMBER_WORDS.iter().enumerate() {
+        for (number, word) in (1..).zip(NUMBER_WORDS) {
             if line[i..].starts_with(word) {
-                return Some((
-                    i,
-                    u32::try_from(word_index).expect("index doesn't fit u32") + 1,
-                ));
+                return Some((i, number));
             }
         }
     }
@@ -116,12 +122,14 @@ impl Parts for Solution {
 #[cfg(test)]
 mod tests {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_01), ignore = "no input")]
         fn outcome() {
-            test_part_1!(54159);
+            test_part_1!();
         }
 
         #[test]
@@ -131,10 +139,10 @@ mod tests {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
-        use crate::{first_word_digit, last_word_digit};
+        use crate::days::day01::{first_word_digit, last_word_digit};
+        use crate::{test_example_part_2, test_part_2};
 
         #[test]
         fn example() {
@@ -142,8 +150,9 @@ mod tests {
         }
 
         #[test]
+        #[cfg_attr(not(has_input_2023_01), ignore = "no input")]
         fn outcome() {
-            test_part_2!(53866);
+            test_part_2!();
         }
 
         #[test]
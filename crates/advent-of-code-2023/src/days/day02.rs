@@ -2,11 +2,12 @@
 
 use std::fmt::Display;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::{PartSolution, Parts};
 
-advent_of_code_2023::solution!(2449, 63981);
+crate::solution!();
 
-fn count_valid_games(lines: &str) -> u32 {
+#[must_use]
+pub fn count_valid_games(lines: &str) -> u32 {
     let mut total = 0;
 
     for line in lines.lines() {
@@ -51,22 +52,23 @@ fn is_valid_game_optimized(line: &str) -> Option<u32> {
     Some(game_number)
 }
 
-fn sum_of_powers(lines: &str) -> u32 {
+#[must_use]
+pub fn sum_of_powers(lines: &str) -> u32 {
     lines.lines().fold(0, |total, line| {
         let game = naive_parse_line(line);
         total + game.get_lowest_amount_of_cubes()
     })
 }
 
-struct Game {
-    game_number: u32,
-    runs: Vec<Cubes>,
+pub struct Game {
+    pub game_number: u32,
+    pub runs: Vec<Cubes>,
 }
 
-struct Cubes {
-    blue: u32,
-    green: u32,
-    red: u32,
+pub struct Cubes {
+    pub blue: u32,
+    pub green: u32,
+    pub red: u32,
 }
 
 impl Display for Game {
@@ -80,7 +82,8 @@ impl Display for Game {
 }
 
 impl Game {
-    fn is_valid(&self) -> bool {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
         for cubes in &self.runs {
             if cubes.blue > 14 || cubes.green > 13 || cubes.red > 12 {
                 return false;
@@ -90,7 +93,8 @@ impl Game {
         true
     }
 
-    fn get_lowest_amount_of_cubes(&self) -> u32 {
+    #[must_use]
+    pub fn get_lowest_amount_of_cubes(&self) -> u32 {
         let mut lowest = Cubes {
             blue: u32::MIN,
             green: u32::MIN,
@@ -107,7 +111,12 @@ impl Game {
     }
 }
 
-fn naive_parse_line(line: &str) -> Game {
+/// Parses a single `Game 1: 3 blue, 4 red; ...` line.
+///
+/// # Panics
+/// When the line is malformed, or mentions a color other than red, green or blue.
+#[must_use]
+pub fn naive_parse_line(line: &str) -> Game {
     let (left, right) = line.split_once(':').expect("Invalid game");
 
     let game_number = left[5..].parse::<u32>().unwrap();
@@ -158,12 +167,14 @@ impl Parts for Solution {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_02), ignore = "no input")]
         fn outcome() {
-            test_part_1!(2449);
+            test_part_1!();
         }
 
         #[test]
@@ -173,12 +184,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_02), ignore = "no input")]
         fn outcome() {
-            test_part_2!(63_981);
+            test_part_2!();
         }
 
         #[test]
@@ -1,8 +1,9 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
 
-advent_of_code_2023::solution!(527_364, 79_026_871);
+crate::solution!();
 
-enum Cell {
+pub enum Cell {
     Number(u32),
     Symbol(char),
     Empty,
@@ -19,28 +20,36 @@ impl TryFrom<char> for Cell {
 
                 Ok(Cell::Number(n))
             },
-            _ => Ok(Cell::Symbol(c)),
+            _ if c.is_ascii_punctuation() => Ok(Cell::Symbol(c)),
+            _ => Err("expected a digit, a symbol or a `.`"),
         }
     }
 }
 
-fn parse_lines(lines: &str) -> Schematic {
+/// Parses the engine schematic.
+///
+/// # Errors
+/// When the schematic contains a character that is not a digit, a symbol or a `.`.
+pub fn parse_lines(lines: &str) -> Result<Schematic, SolveError> {
     let mut parsed = Vec::new();
 
-    for line in lines.lines() {
+    for (line_index, line) in lines.lines().enumerate() {
         let mut row = Vec::new();
 
-        for c in line.chars() {
-            row.push(Cell::try_from(c).unwrap());
+        for (column_index, c) in line.chars().enumerate() {
+            let cell = Cell::try_from(c)
+                .map_err(|reason| SolveError::parse_at(line_index, column_index, reason))?;
+
+            row.push(cell);
         }
 
         parsed.push(row);
     }
 
-    parsed
+    Ok(parsed)
 }
 
-type Schematic = Vec<Vec<Cell>>;
+pub type Schematic = Vec<Vec<Cell>>;
 
 fn find_number_at(row: &[Cell], column_index: usize) -> Option<u32> {
     // first we check if we are at the start of our number
@@ -77,7 +86,12 @@ fn find_number_at(row: &[Cell], column_index: usize) -> Option<u32> {
     Some(number)
 }
 
-fn get_numbers_around(schematic: &Schematic, row_index: usize, column_index: usize) -> Vec<u32> {
+#[must_use]
+pub fn get_numbers_around(
+    schematic: &Schematic,
+    row_index: usize,
+    column_index: usize,
+) -> Vec<u32> {
     let mut numbers_around = vec![];
 
     let row_above = row_index.checked_sub(1).and_then(|ri| schematic.get(ri));
@@ -146,7 +160,8 @@ fn get_numbers_around(schematic: &Schematic, row_index: usize, column_index: usi
     numbers_around
 }
 
-fn multiply_gear_numbers(schematic: &Schematic) -> u32 {
+#[must_use]
+pub fn multiply_gear_numbers(schematic: &Schematic) -> u32 {
     let mut sum = 0;
 
     for (row_index, row) in schematic.iter().enumerate() {
@@ -164,7 +179,8 @@ fn multiply_gear_numbers(schematic: &Schematic) -> u32 {
     sum
 }
 
-fn sum_all_part_numbers(schematic: &Schematic) -> u32 {
+#[must_use]
+pub fn sum_all_part_numbers(schematic: &Schematic) -> u32 {
     let mut sum = 0;
 
     for (row_index, row) in schematic.iter().enumerate() {
@@ -180,29 +196,33 @@ fn sum_all_part_numbers(schematic: &Schematic) -> u32 {
     sum
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
+impl ParsedParts for Solution {
+    type Input = Schematic;
 
-        sum_all_part_numbers(&parsed).into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        sum_all_part_numbers(input).into()
+    }
 
-        multiply_gear_numbers(&parsed).into()
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        multiply_gear_numbers(input).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_03), ignore = "no input")]
         fn outcome() {
-            test_part_1!(527_364);
+            test_part_1!();
         }
 
         #[test]
@@ -212,12 +232,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_03), ignore = "no input")]
         fn outcome() {
-            test_part_2!(79_026_871);
+            test_part_2!();
         }
 
         #[test]
@@ -225,4 +247,24 @@ mod test {
             test_example_part_2!(467_835);
         }
     }
+
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn invalid_character() {
+            assert_eq!(
+                Solution {}.try_part_1("467..\n...a.").unwrap_err(),
+                SolveError::Parse {
+                    line: 2,
+                    column: 4,
+                    reason: "expected a digit, a symbol or a `.`".into(),
+                }
+            );
+        }
+    }
 }
@@ -0,0 +1,205 @@
+use std::hash::{Hash, Hasher};
+
+use hashbrown::{HashMap, HashSet};
+
+crate::solution!();
+
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+pub struct Game {
+    pub game_number: usize,
+    pub intersection_count: usize,
+}
+
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.game_number.hash(state);
+    }
+}
+
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        self.game_number == other.game_number
+    }
+}
+
+impl Eq for Game {}
+
+/// Parses the scratchcards, counting the winning numbers on each one.
+///
+/// # Errors
+/// When a card is missing its `:` or `|` separator, or its number, or has something other than
+/// numbers on it.
+pub fn parse_lines(lines: &str) -> Result<Vec<Game>, SolveError> {
+    let mut games = Vec::new();
+
+    for (line_index, line) in lines.lines().enumerate() {
+        let game =
+            parse_game(line).map_err(|reason| SolveError::parse_at(line_index, 0, reason))?;
+
+        games.push(game);
+    }
+
+    Ok(games)
+}
+
+fn parse_game(line: &str) -> Result<Game, &'static str> {
+    let (game, all_numbers) = line.split_once(':').ok_or("Invalid game")?;
+
+    let (winning_numbers, my_numbers) = all_numbers.split_once('|').ok_or("Invalid numbers")?;
+
+    let intersection_count = split_string_into_numbers(winning_numbers)?
+        .intersection(&split_string_into_numbers(my_numbers)?)
+        .count();
+
+    Ok(Game {
+        game_number: parse_game_number(game)?,
+        intersection_count,
+    })
+}
+
+#[must_use]
+pub fn get_scores(games: &[Game]) -> usize {
+    let mut scores = 0;
+
+    for game in games {
+        let score = game.get_score();
+
+        scores += score;
+    }
+
+    scores
+}
+
+impl Game {
+    /// Calculates the points a card is worth, doubling for every winning number after the first.
+    ///
+    /// # Panics
+    /// When the card has more winning numbers than fit in a `u32`.
+    #[must_use]
+    pub fn get_score(&self) -> usize {
+        let intersection: u32 = self
+            .get_intersection_count()
+            .try_into()
+            .expect("Intersection too large");
+
+        intersection.checked_sub(1).map_or(0, |i| 2_usize.pow(i))
+    }
+
+    fn get_intersection_count(&self) -> usize {
+        self.intersection_count
+    }
+}
+
+fn parse_game_number(game: &str) -> Result<usize, &'static str> {
+    let (_, game_number) = game.split_once(' ').ok_or("Invalid game")?;
+
+    game_number
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| "Invalid game number")
+}
+
+#[must_use]
+pub fn count_played_cards(games: &[Game]) -> u32 {
+    let mut cache: HashMap<&Game, u32> = HashMap::new();
+
+    count_played_cards_r(Some(games), true, &mut cache)
+}
+
+fn count_played_cards_r<'games>(
+    games: Option<&'games [Game]>,
+    base_run: bool,
+    cache: &mut HashMap<&'games Game, u32>,
+) -> u32 {
+    let Some(games) = games else {
+        return 0;
+    };
+
+    let Some(game) = games.first() else {
+        return 0;
+    };
+
+    if let Some(c) = cache.get(&game) {
+        return *c;
+    }
+
+    let mut total_cards = 1;
+
+    for offset in 0..game.get_intersection_count() {
+        total_cards += count_played_cards_r(games.get(1 + offset..), false, cache);
+    }
+
+    cache.insert(game, total_cards);
+
+    // there is one base run where we always advance
+    if base_run {
+        for i in 1..games.len() {
+            total_cards += count_played_cards_r(games.get(i..), false, cache);
+        }
+    }
+
+    total_cards
+}
+
+fn split_string_into_numbers(numbers: &str) -> Result<HashSet<u32>, &'static str> {
+    numbers
+        .split(' ')
+        .filter(|n| !n.is_empty())
+        .map(|n| n.trim().parse::<u32>().map_err(|_| "Invalid number"))
+        .collect()
+}
+
+impl ParsedParts for Solution {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        get_scores(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        count_played_cards(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_1, test_part_1};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_04), ignore = "no input")]
+        fn outcome() {
+            test_part_1!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_1!(13);
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_2, test_part_2};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_04), ignore = "no input")]
+        fn outcome() {
+            test_part_2!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_2!(30);
+        }
+    }
+}
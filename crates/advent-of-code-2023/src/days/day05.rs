@@ -2,19 +2,19 @@ use std::convert::Into;
 use std::fmt::Debug;
 use std::ops::Range;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
-
 use self::parse::{IndividualSeeds, RangeOfSeeds};
+use crate::shared::{PartSolution, Parts};
 
-advent_of_code_2023::solution!(309_796_150, 50_716_416);
+crate::solution!();
 
 #[derive(Debug, PartialEq, Eq)]
-struct Almanac {
+pub struct Almanac {
     maps: Vec<AlmanacMap>,
 }
 
 impl Almanac {
-    fn all_the_way(&self, mut seed: u64) -> u64 {
+    #[must_use]
+    pub fn all_the_way(&self, mut seed: u64) -> u64 {
         for map in &self.maps {
             seed = map.map(seed);
         }
@@ -22,7 +22,11 @@ impl Almanac {
         seed
     }
 
-    fn remap_seed_ranges_all_the_way(&self, mut seed_ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    #[must_use]
+    pub fn remap_seed_ranges_all_the_way(
+        &self,
+        mut seed_ranges: Vec<Range<u64>>,
+    ) -> Vec<Range<u64>> {
         for map in &self.maps {
             seed_ranges = map.remap_seed_ranges(seed_ranges);
         }
@@ -40,12 +44,13 @@ impl From<Vec<Vec<(u64, u64, u64)>>> for Almanac {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct AlmanacMap {
+pub struct AlmanacMap {
     ranges: Vec<DestinationSourceRange>,
 }
 
 impl AlmanacMap {
-    fn map(&self, seed: u64) -> u64 {
+    #[must_use]
+    pub fn map(&self, seed: u64) -> u64 {
         for range in &self.ranges {
             if let Some(mapped_seed) = range.map(seed) {
                 return mapped_seed;
@@ -55,7 +60,12 @@ impl AlmanacMap {
         seed
     }
 
-    fn remap_seed_ranges(&self, seed_ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    /// Runs the seed ranges through this map, splitting them where they overlap partially.
+    ///
+    /// # Panics
+    /// When a range overlaps with the map in a way we didn't account for.
+    #[must_use]
+    pub fn remap_seed_ranges(&self, seed_ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
         // run the seed_range through the mapping ONCE, splitting it into processed and unprocessed
         let mut processed = vec![]; // these have been split off and remapped
 
@@ -231,17 +241,17 @@ impl std::fmt::Debug for DestinationSourceRange {
     }
 }
 
-mod parse {
+pub mod parse {
     use std::ops::Range;
 
     use super::{Almanac, AlmanacMap};
 
-    pub(super) trait IntoSeed {
+    pub trait IntoSeed {
         fn into(raw_seeds: Vec<u64>) -> Self;
     }
 
     #[derive(Debug, PartialEq, Eq)]
-    pub(super) struct IndividualSeeds(pub Vec<u64>);
+    pub struct IndividualSeeds(pub Vec<u64>);
 
     impl IntoSeed for IndividualSeeds {
         fn into(raw_seeds: Vec<u64>) -> Self {
@@ -250,7 +260,7 @@ mod parse {
     }
 
     #[derive(Debug, PartialEq, Eq)]
-    pub(super) struct RangeOfSeeds(pub Vec<Range<u64>>);
+    pub struct RangeOfSeeds(pub Vec<Range<u64>>);
 
     impl IntoSeed for RangeOfSeeds {
         fn into(raw_seeds: Vec<u64>) -> Self {
@@ -265,7 +275,12 @@ mod parse {
             .collect::<Vec<Range<u64>>>()
     }
 
-    pub(super) fn parse_lines<S: IntoSeed>(lines: &str) -> (Almanac, S) {
+    /// Parses the almanac, interpreting the seeds line as `S`.
+    ///
+    /// # Panics
+    /// When a section is unknown, or when the seeds or one of the maps is missing.
+    #[must_use]
+    pub fn parse_lines<S: IntoSeed>(lines: &str) -> (Almanac, S) {
         let mut seeds = None;
         let mut seed_to_soil_map = None;
         let mut soil_to_fertilizer_map = None;
@@ -392,21 +407,22 @@ impl Parts for Solution {
 #[cfg(test)]
 mod tests {
     mod part_1 {
-        use advent_of_code_2023::shared::solution::read_file;
-        use advent_of_code_2023::test_part_1;
         use pretty_assertions::assert_eq;
 
-        use crate::parse::{IndividualSeeds, parse_lines};
-        use crate::{Almanac, DAY};
+        use crate::days::day05::parse::{IndividualSeeds, parse_lines};
+        use crate::days::day05::{Almanac, PUZZLE};
+        use crate::shared::solution::read_file;
+        use crate::test_part_1;
 
         #[test]
+        #[cfg_attr(not(has_input_2023_05), ignore = "no input")]
         fn outcome() {
-            test_part_1!(309_796_150);
+            test_part_1!();
         }
 
         #[test]
         fn example() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (almanac, seeds) = parse_lines::<IndividualSeeds>(&lines);
 
@@ -428,7 +444,7 @@ mod tests {
 
         #[test]
         fn example_seed_to_soil() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (almanac, _) = parse_lines::<IndividualSeeds>(&lines);
 
@@ -440,7 +456,7 @@ mod tests {
 
         #[test]
         fn example_seed_to_location() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (almanac, _) = parse_lines::<IndividualSeeds>(&lines);
 
@@ -455,7 +471,7 @@ mod tests {
 
         #[test]
         fn seed_maps() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (almanac, seeds) = parse_lines::<IndividualSeeds>(&lines);
 
@@ -472,21 +488,22 @@ mod tests {
     mod part_2 {
         use std::ops::Range;
 
-        use advent_of_code_2023::shared::solution::read_file;
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
-        use crate::parse::{RangeOfSeeds, parse_lines};
-        use crate::{AlmanacMap, DAY};
+        use crate::days::day05::parse::{RangeOfSeeds, parse_lines};
+        use crate::days::day05::{AlmanacMap, PUZZLE};
+        use crate::shared::solution::read_file;
+        use crate::{test_example_part_2, test_part_2};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_05), ignore = "no input")]
         fn outcome() {
-            test_part_2!(50_716_416);
+            test_part_2!();
         }
 
         #[test]
         fn example_test_ranges() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (_, range_of_seeds) = parse_lines::<RangeOfSeeds>(&lines);
 
@@ -495,7 +512,7 @@ mod tests {
 
         #[test]
         fn example_test_min_ranges_naive() {
-            let lines = read_file("examples", &DAY);
+            let lines = read_file("examples", &PUZZLE);
 
             let (almanac, range_of_seeds) = parse_lines::<RangeOfSeeds>(&lines);
 
@@ -1,10 +1,15 @@
 use std::convert::Into as _;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::{PartSolution, Parts};
 
-advent_of_code_2023::solution!(345_015, 42_588_603);
+crate::solution!();
 
-fn parse_lines(input: &str) -> Vec<(usize, usize)> {
+/// Parses the races as pairs of time and record distance.
+///
+/// # Panics
+/// When the time or distance line is missing or contains something other than numbers.
+#[must_use]
+pub fn parse_lines(input: &str) -> Vec<(usize, usize)> {
     let mut time_and_distances = Vec::new();
     let lines = input.lines().collect::<Vec<&str>>();
 
@@ -24,7 +29,12 @@ fn parse_lines(input: &str) -> Vec<(usize, usize)> {
     time_and_distances
 }
 
-fn parse_lines_with_bad_kerning(input: &str) -> (usize, usize) {
+/// Parses the input as a single race, ignoring the spaces between the digits.
+///
+/// # Panics
+/// When the time or distance line is missing or contains something other than numbers.
+#[must_use]
+pub fn parse_lines_with_bad_kerning(input: &str) -> (usize, usize) {
     let lines = input.lines().collect::<Vec<&str>>();
 
     let (_, times) = lines[0].split_once(':').expect("Bad input");
@@ -63,7 +73,8 @@ impl Parts for Solution {
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss
 )]
-fn calculate_possibilities(time: usize, distance: usize) -> usize {
+#[must_use]
+pub fn calculate_possibilities(time: usize, distance: usize) -> usize {
     // solve the quadratic formula of
 
     // hold = hold time
@@ -114,12 +125,14 @@ fn calculate_possibilities(time: usize, distance: usize) -> usize {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_06), ignore = "no input")]
         fn outcome() {
-            test_part_1!(345_015);
+            test_part_1!();
         }
 
         #[test]
@@ -129,12 +142,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_06), ignore = "no input")]
         fn outcome() {
-            test_part_2!(42_588_603);
+            test_part_2!();
         }
 
         #[test]
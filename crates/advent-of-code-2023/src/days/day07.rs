@@ -1,12 +1,13 @@
 use std::cmp::Ordering;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::HashMap;
 
-advent_of_code_2023::solution!(248_559_379, 249_631_254);
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
 
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
-enum Card {
+pub enum Card {
     Ace = 14,
     King = 13,
     Queen = 12,
@@ -51,13 +52,13 @@ impl TryFrom<char> for Card {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Hand {
-    cards: Vec<Card>,
-    bid: usize,
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub bid: usize,
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Type {
+pub enum Type {
     FiveOfAKind = 6,
     FourOfAKind = 5,
     FullHouse = 4,
@@ -68,7 +69,12 @@ enum Type {
 }
 
 impl Hand {
-    fn get_type(&self) -> Type {
+    /// Determines the type of the hand, letting wildcards count as the most frequent card.
+    ///
+    /// # Panics
+    /// When the counts of the cards are inconsistent with the hand.
+    #[must_use]
+    pub fn get_type(&self) -> Type {
         let mut counts = HashMap::<&Card, usize>::new();
         self.cards.iter().for_each(|c| {
             if let Some(cc) = counts.get_mut(c) {
@@ -140,13 +146,19 @@ impl Ord for Hand {
     }
 }
 
-fn parse_game(game: &str) -> Vec<Card> {
+/// Parses the cards of a hand.
+///
+/// # Panics
+/// When a character is not a valid card.
+#[must_use]
+pub fn parse_game(game: &str) -> Vec<Card> {
     game.chars()
         .map(|c| c.try_into().expect("Invalid character"))
         .collect::<Vec<Card>>()
 }
 
-fn parse_game_jack_is_wildcard(game: &str) -> Vec<Card> {
+#[must_use]
+pub fn parse_game_jack_is_wildcard(game: &str) -> Vec<Card> {
     let mut parsed = parse_game(game);
 
     for card in &mut parsed {
@@ -175,11 +187,11 @@ fn parse_hands_with_callback(input: &str, parse_game_fn: fn(&str) -> Vec<Card>)
     hands
 }
 
-fn parse_hands(input: &str) -> Vec<Hand> {
+pub fn parse_hands(input: &str) -> Vec<Hand> {
     parse_hands_with_callback(input, parse_game)
 }
 
-fn parse_hands_jack_is_wildcard(input: &str) -> Vec<Hand> {
+pub fn parse_hands_jack_is_wildcard(input: &str) -> Vec<Hand> {
     parse_hands_with_callback(input, parse_game_jack_is_wildcard)
 }
 
@@ -212,15 +224,16 @@ impl Parts for Solution {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::shared::solution::read_file;
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
-        use crate::{Card, DAY, Hand, Type, parse_game, parse_hands};
+        use crate::days::day07::{Card, Hand, PUZZLE, Type, parse_game, parse_hands};
+        use crate::shared::solution::read_file;
+        use crate::{test_example_part_1, test_part_1};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_07), ignore = "no input")]
         fn outcome() {
-            test_part_1!(248_559_379);
+            test_part_1!();
         }
 
         #[test]
@@ -230,7 +243,7 @@ mod test {
 
         #[test]
         fn parse_example() {
-            let input = &read_file("examples", &DAY);
+            let input = &read_file("examples", &PUZZLE);
 
             let hands = parse_hands(input.as_str());
 
@@ -381,7 +394,7 @@ mod test {
 
         #[test]
         fn example_sort_order() {
-            let input = &read_file("examples", &DAY);
+            let input = &read_file("examples", &PUZZLE);
 
             let mut hands = parse_hands(input);
 
@@ -416,14 +429,15 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
-        use crate::{Hand, Type, parse_game, parse_game_jack_is_wildcard};
+        use crate::days::day07::{Hand, Type, parse_game, parse_game_jack_is_wildcard};
+        use crate::{test_example_part_2, test_part_2};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_07), ignore = "no input")]
         fn outcome() {
-            test_part_2!(249_631_254);
+            test_part_2!();
         }
 
         #[test]
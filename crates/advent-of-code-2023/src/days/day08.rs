@@ -0,0 +1,263 @@
+use std::array::TryFromSliceError;
+use std::collections::BTreeMap;
+
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+pub enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(format!("Couldn't convert '{}' into Direction", value)),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Node([char; 3]);
+
+impl Node {
+    fn is_end(&self) -> bool {
+        self.0[2] == 'Z'
+    }
+
+    fn is_start(&self) -> bool {
+        self.0[2] == 'A'
+    }
+}
+
+impl TryFrom<&[char]> for Node {
+    type Error = TryFromSliceError;
+
+    fn try_from(value: &[char]) -> Result<Self, Self::Error> {
+        let x: [char; 3] = value.try_into()?;
+
+        Ok(Node(x))
+    }
+}
+
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: BTreeMap<Node, (Node, Node)>,
+}
+
+fn parse_node(as_chars: &[char], column_index: usize) -> Option<Node> {
+    let node = as_chars.get(column_index..column_index + 3)?;
+
+    node.try_into().ok()
+}
+
+fn parse_node_line(line_index: usize, node_line: &str) -> Result<(Node, (Node, Node)), SolveError> {
+    let as_chars = node_line.chars().collect::<Vec<char>>();
+    // AAA = (BBB, CCC)
+    let node_at = |column_index| {
+        parse_node(&as_chars, column_index)
+            .ok_or_else(|| SolveError::parse_at(line_index, column_index, "Invalid node"))
+    };
+
+    let from_node = node_at(0)?;
+
+    let to_node_left = node_at(7)?;
+    let to_node_right = node_at(12)?;
+
+    Ok((from_node, (to_node_left, to_node_right)))
+}
+
+/// Parses the directions and the nodes of the network.
+///
+/// # Errors
+/// When the directions are not followed by an empty line, or a direction or a node is malformed.
+pub fn parse_lines(input: &str) -> Result<Network, SolveError> {
+    let (unparsed_directions, unparsed_node_lines) = input
+        .split_once("\n\n")
+        .ok_or_else(|| SolveError::parse_at(0, 0, "Invalid network"))?;
+
+    let directions = unparsed_directions
+        .chars()
+        .enumerate()
+        .map(|(column_index, c)| {
+            Direction::try_from(c).map_err(|reason| SolveError::parse_at(0, column_index, reason))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // the nodes start after the directions and the empty line
+    let first_node_line = unparsed_directions.lines().count() + 1;
+
+    let nodes = unparsed_node_lines
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_node_line(first_node_line + index, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Network { directions, nodes })
+}
+
+/// Counts the steps needed to get from `AAA` to `ZZZ`.
+///
+/// # Panics
+/// When we end up at a node that is not in the network.
+#[must_use]
+pub fn follow_directions(network: &Network) -> usize {
+    const START: Node = Node(['A', 'A', 'A']);
+    const END: Node = Node(['Z', 'Z', 'Z']);
+
+    let mut steps = 0;
+    let mut current: &Node = &START;
+
+    for direction in network.directions.iter().cycle() {
+        if current == &END {
+            break;
+        }
+        steps += 1;
+
+        let next = network.nodes.get(current).expect("No next?");
+
+        match *direction {
+            Direction::Left => current = &next.0,
+            Direction::Right => current = &next.1,
+        }
+    }
+
+    steps
+}
+
+fn count_steps<'node, 'network>(mut current: &'node Node, network: &'network Network) -> usize
+where
+    'network: 'node,
+{
+    let mut steps = 0;
+
+    while !current.is_end() {
+        let next = network.nodes.get(current).expect("No next?");
+
+        match network.directions.get(steps % network.directions.len()) {
+            Some(&Direction::Left) => current = &next.0,
+            Some(&Direction::Right) => current = &next.1,
+            None => panic!("Not found"),
+        }
+
+        steps += 1;
+    }
+
+    steps
+}
+
+fn gcd(left: usize, right: usize) -> usize {
+    if right == 0 {
+        left
+    } else {
+        gcd(right, left % right)
+    }
+}
+
+fn lcm(left: usize, right: usize) -> usize {
+    (left * right) / gcd(left, right)
+}
+
+/// Counts the steps needed until every start has reached an end at the same time.
+///
+/// # Panics
+/// When `starts` is empty.
+pub fn follow_directions_from_multiple(network: &Network, starts: Vec<&Node>) -> usize {
+    starts
+        .into_iter()
+        .map(|node| count_steps(node, network))
+        .reduce(lcm)
+        .unwrap()
+}
+
+#[must_use]
+pub fn find_starts(nodes: &BTreeMap<Node, (Node, Node)>) -> Vec<&Node> {
+    nodes
+        .keys()
+        .filter(|f| f.is_start())
+        .collect::<Vec<&Node>>()
+}
+
+impl ParsedParts for Solution {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        follow_directions(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let starts = find_starts(&input.nodes);
+
+        follow_directions_from_multiple(input, starts).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_1, test_part_1};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_08), ignore = "no input")]
+        fn outcome() {
+            test_part_1!();
+        }
+
+        #[test]
+        fn example_1() {
+            test_example_part_1!(2, 1);
+        }
+
+        #[test]
+        fn example_2() {
+            test_example_part_1!(6, 2);
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_2, test_part_2};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_08), ignore = "no input")]
+        fn outcome() {
+            test_part_2!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_2!(6, 3);
+        }
+    }
+
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn invalid_node() {
+            assert_eq!(
+                Solution {}
+                    .try_part_1("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA\n")
+                    .unwrap_err(),
+                SolveError::parse_at(3, 12, "Invalid node")
+            );
+        }
+    }
+}
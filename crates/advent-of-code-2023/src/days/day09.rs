@@ -1,16 +1,25 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
 
-advent_of_code_2023::solution!(1_772_145_754, 867);
+crate::solution!();
 
-fn parse_lines(input: &str) -> Vec<Vec<i32>> {
+/// Parses every line as a history of values.
+///
+/// # Errors
+/// When a value is not a number.
+pub fn parse_lines(input: &str) -> Result<Vec<Vec<i32>>, SolveError> {
     input
         .lines()
-        .map(|line| {
+        .enumerate()
+        .map(|(line_index, line)| {
             line.split(' ')
-                .map(|v| v.parse().unwrap())
-                .collect::<Vec<i32>>()
+                .map(|v| {
+                    v.parse()
+                        .map_err(|_| SolveError::parse_at(line_index, 0, "expected a number"))
+                })
+                .collect::<Result<Vec<i32>, _>>()
         })
-        .collect::<Vec<Vec<i32>>>()
+        .collect::<Result<Vec<Vec<i32>>, _>>()
 }
 
 fn calculate_future_r(history: &[i32]) -> i32 {
@@ -29,7 +38,8 @@ fn calculate_future_r(history: &[i32]) -> i32 {
     }
 }
 
-fn calculate_sum_of_futures(histories: &[Vec<i32>]) -> i32 {
+#[must_use]
+pub fn calculate_sum_of_futures(histories: &[Vec<i32>]) -> i32 {
     let mut sum = 0;
     for history in histories {
         sum += calculate_future_r(history);
@@ -53,7 +63,8 @@ fn calculate_history_r(history: &[i32]) -> i32 {
     }
 }
 
-fn calculate_sum_of_histories(histories: &[Vec<i32>]) -> i32 {
+#[must_use]
+pub fn calculate_sum_of_histories(histories: &[Vec<i32>]) -> i32 {
     let mut sum = 0;
     for history in histories {
         sum += calculate_history_r(history);
@@ -61,29 +72,33 @@ fn calculate_sum_of_histories(histories: &[Vec<i32>]) -> i32 {
     sum
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
+impl ParsedParts for Solution {
+    type Input = Vec<Vec<i32>>;
 
-        calculate_sum_of_futures(&parsed).into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        calculate_sum_of_futures(input).into()
+    }
 
-        calculate_sum_of_histories(&parsed).into()
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        calculate_sum_of_histories(input).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_09), ignore = "no input")]
         fn outcome() {
-            test_part_1!(1_772_145_754);
+            test_part_1!();
         }
 
         #[test]
@@ -93,12 +108,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_09), ignore = "no input")]
         fn outcome() {
-            test_part_2!(867);
+            test_part_2!();
         }
 
         #[test]
@@ -1,6 +1,8 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::error::SolveError;
+use crate::shared::grids::grid::Grid;
+use crate::shared::{ParsedParts, PartSolution};
 
-advent_of_code_2023::solution!(6956, 455);
+crate::solution!();
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum Direction {
@@ -11,7 +13,7 @@ enum Direction {
 }
 
 #[derive(Copy, Clone)]
-enum Tile {
+pub enum Tile {
     Vertical(bool),
     Horizontal(bool),
     NorthEast(bool),
@@ -19,10 +21,14 @@ enum Tile {
     SouthWest(bool),
     SouthEast(bool),
     Ground,
+    /// Where the animal starts, until we know which pipe is beneath it.
+    Start,
 }
 
-impl Tile {
-    fn from_board_value(value: char, start_piece: Tile) -> Result<Tile, &'static str> {
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '|' => Ok(Tile::Vertical(false)),
             '-' => Ok(Tile::Horizontal(false)),
@@ -31,83 +37,98 @@ impl Tile {
             '7' => Ok(Tile::SouthWest(false)),
             'F' => Ok(Tile::SouthEast(false)),
             '.' => Ok(Tile::Ground),
-            'S' => Ok(start_piece),
+            'S' => Ok(Tile::Start),
             _ => Err("Invalid character"),
         }
     }
 }
 
-fn split_input(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .map(|l| l.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<_>>>()
-}
-
-fn parse_lines(lines: Vec<Vec<char>>, start_piece: Tile) -> Vec<Vec<Tile>> {
-    lines
-        .into_iter()
-        .map(|line| {
-            line.into_iter()
-                .map(|c| Tile::from_board_value(c, start_piece).unwrap())
-                .collect::<Vec<_>>()
+/// Finds the position of `S`, and the pipe it must be based on its neighbors.
+///
+/// # Errors
+/// When there is no `S`, or when it doesn't connect to exactly 2 neighbors.
+pub fn find_start_piece(map: &Grid<Tile>) -> Result<((usize, usize), Tile), SolveError> {
+    let (i, j) = map
+        .rows()
+        .enumerate()
+        .find_map(|(i, row)| {
+            row.iter()
+                .position(|t| matches!(*t, Tile::Start))
+                .map(|j| (i, j))
         })
-        .collect::<Vec<_>>()
+        .ok_or_else(|| SolveError::parse_at(0, 0, "Start not found"))?;
+
+    let left = (j.checked_sub(1)).is_some_and(|jj| {
+        matches!(
+            map.get(i, jj),
+            Some(&(Tile::Horizontal(_) | Tile::NorthEast(_) | Tile::SouthEast(_)))
+        )
+    });
+
+    let right = matches!(
+        map.get(i, j + 1),
+        Some(&(Tile::Horizontal(_) | Tile::NorthWest(_) | Tile::SouthWest(_)))
+    );
+
+    let up = (i.checked_sub(1)).is_some_and(|ii| {
+        matches!(
+            map.get(ii, j),
+            Some(&(Tile::Vertical(_) | Tile::SouthEast(_) | Tile::SouthWest(_)))
+        )
+    });
+
+    let down = matches!(
+        map.get(i + 1, j),
+        Some(&(Tile::Vertical(_) | Tile::NorthEast(_) | Tile::NorthWest(_)))
+    );
+
+    let start = match (up, down, left, right) {
+        // coming from top to bottom
+        (true, true, false, false) => Tile::Vertical(true),
+        // coming from top to left
+        (true, false, true, false) => Tile::NorthWest(true),
+        // top to right
+        (true, false, false, true) => Tile::NorthEast(true),
+        // coming from below to left
+        (false, true, true, false) => Tile::SouthWest(true),
+        // coming from below to right
+        (false, true, false, true) => Tile::SouthEast(true),
+        // coming from left to right
+        (false, false, true, true) => Tile::Horizontal(true),
+        _ => {
+            return Err(SolveError::parse_at(
+                i,
+                j,
+                "Invalid S, it must connect to exactly 2 neighbors",
+            ));
+        },
+    };
+
+    Ok(((i, j), start))
 }
 
-fn find_start_piece(map: &[Vec<char>]) -> ((usize, usize), Tile) {
-    for (i, line) in map.iter().enumerate() {
-        for (j, column) in line.iter().enumerate() {
-            if column != &'S' {
-                continue;
-            }
-
-            let left = (j.checked_sub(1)).is_some_and(|jj| ['-', 'L', 'F'].contains(&line[jj]));
-
-            let right = if j + 1 < line.len() {
-                ['-', 'J', '7'].contains(&line[j + 1])
-            } else {
-                false
-            };
-
-            let up = (i.checked_sub(1)).is_some_and(|ii| ['|', 'F', '7'].contains(&map[ii][j]));
-
-            let down = if i + 1 < map.len() {
-                ['|', 'L', 'J'].contains(&map[i + 1][j])
-            } else {
-                false
-            };
-
-            let start = match (up, down, left, right) {
-                // coming from top to bottom
-                (true, true, false, false) => Tile::Vertical(true),
-                // coming from top to left
-                (true, false, true, false) => Tile::NorthWest(true),
-                // top to right
-                (true, false, false, true) => Tile::NorthEast(true),
-                // coming from below to left
-                (false, true, true, false) => Tile::SouthWest(true),
-                // coming from below to right
-                (false, true, false, true) => Tile::SouthEast(true),
-                // coming from left to right
-                (false, false, true, true) => Tile::Horizontal(true),
-                _ => panic!("Invalid S"),
-            };
-
-            return ((i, j), start);
-        }
-    }
+/// Parses the tiles, and replaces `S` with the pipe beneath it.
+///
+/// # Errors
+/// When a character is not a known tile, or the rows differ in width, or there is no valid `S`.
+pub fn parse_input(input: &str) -> Result<((usize, usize), Grid<Tile>), SolveError> {
+    let mut map = Grid::parse(input)?;
+
+    let (start, start_piece) = find_start_piece(&map)?;
+
+    map[start] = start_piece;
 
-    panic!("Start not found");
+    Ok((start, map))
 }
 
-fn get_any_start_direction(map: &[Vec<Tile>], start: &(usize, usize)) -> Direction {
+fn get_any_start_direction(map: &Grid<Tile>, start: &(usize, usize)) -> Direction {
     match map[start.0][start.1] {
         Tile::Vertical(true) | Tile::SouthEast(true) => Direction::Up,
         Tile::Horizontal(true) | Tile::SouthWest(true) => Direction::Right,
         Tile::NorthWest(true) => Direction::Down,
         Tile::NorthEast(true) => Direction::Left,
         Tile::Ground => panic!("We never start on ground"),
+        Tile::Start => panic!("Start tile should be replaced by its pipe"),
         Tile::Vertical(_)
         | Tile::Horizontal(_)
         | Tile::NorthEast(_)
@@ -117,7 +138,7 @@ fn get_any_start_direction(map: &[Vec<Tile>], start: &(usize, usize)) -> Directi
     }
 }
 
-fn next_direction(map: &[Vec<Tile>], from: Direction, start: &(usize, usize)) -> Direction {
+fn next_direction(map: &Grid<Tile>, from: Direction, start: &(usize, usize)) -> Direction {
     let current = &map[start.0][start.1];
 
     match (from, *current) {
@@ -149,7 +170,7 @@ fn next_coordinates(
     }
 }
 
-fn mark_coordinates_as_part_of_loop(map: &mut [Vec<Tile>], coordinates: (usize, usize)) {
+fn mark_coordinates_as_part_of_loop(map: &mut Grid<Tile>, coordinates: (usize, usize)) {
     let new_tile = match map[coordinates.0][coordinates.1] {
         Tile::Vertical(_) => Tile::Vertical(true),
         Tile::Horizontal(_) => Tile::Horizontal(true),
@@ -158,12 +179,13 @@ fn mark_coordinates_as_part_of_loop(map: &mut [Vec<Tile>], coordinates: (usize,
         Tile::SouthWest(_) => Tile::SouthWest(true),
         Tile::SouthEast(_) => Tile::SouthEast(true),
         Tile::Ground => panic!("Ground cannot be part of loop"),
+        Tile::Start => panic!("Start tile should be replaced by its pipe"),
     };
 
     map[coordinates.0][coordinates.1] = new_tile;
 }
 
-fn mark_loop(map: &mut [Vec<Tile>], start: (usize, usize)) -> (Vec<(usize, usize)>, usize) {
+pub fn mark_loop(map: &mut Grid<Tile>, start: (usize, usize)) -> (Vec<(usize, usize)>, usize) {
     let mut from = get_any_start_direction(map, &start);
 
     let mut current = start;
@@ -218,45 +240,46 @@ pub(crate) fn shoelace(coordinates: &[(usize, usize)], perimeter: usize) -> usiz
     TryInto::<usize>::try_into((size / 2).abs()).unwrap() - (perimeter / 2) + 1
 }
 
-fn count_enclosed(map: &mut [Vec<Tile>], start: (usize, usize)) -> usize {
+pub fn count_enclosed(map: &mut Grid<Tile>, start: (usize, usize)) -> usize {
     let (coordinates, perimeter) = mark_loop(map, start);
 
     shoelace(&coordinates, perimeter)
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let lines = split_input(input);
+impl ParsedParts for Solution {
+    /// Where the animal starts, and the tiles, with `S` replaced by the pipe beneath it.
+    type Input = ((usize, usize), Grid<Tile>);
 
-        let (start, start_piece) = find_start_piece(&lines);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
 
-        let mut map = parse_lines(lines, start_piece);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let &(start, ref map) = input;
 
-        let (_, steps) = mark_loop(&mut map, start);
+        let (_, steps) = mark_loop(&mut map.clone(), start);
 
         ((steps) / 2).into()
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let lines = split_input(input);
-
-        let (start, start_piece) = find_start_piece(&lines);
-
-        let mut map = parse_lines(lines, start_piece);
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let &(start, ref map) = input;
 
-        count_enclosed(&mut map, start).into()
+        count_enclosed(&mut map.clone(), start).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_10), ignore = "no input")]
         fn outcome() {
-            test_part_1!(6956);
+            test_part_1!();
         }
 
         #[test]
@@ -281,12 +304,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_10), ignore = "no input")]
         fn outcome() {
-            test_part_2!(455);
+            test_part_2!();
         }
 
         #[test]
@@ -304,4 +329,28 @@ mod test {
             test_example_part_2!(10, 7);
         }
     }
+
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn start_not_found() {
+            assert_eq!(
+                Solution {}.try_part_1(".F7\n.LJ").unwrap_err(),
+                SolveError::parse_at(0, 0, "Start not found")
+            );
+        }
+
+        #[test]
+        fn invalid_character() {
+            assert_eq!(
+                Solution {}.try_part_1("S7\nL?").unwrap_err(),
+                SolveError::parse_at(1, 1, "invalid cell `?`: Invalid character")
+            );
+        }
+    }
 }
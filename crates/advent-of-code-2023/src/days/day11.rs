@@ -1,29 +1,53 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
 
-advent_of_code_2023::solution!(10_276_166, 598_693_078_798_usize);
+crate::solution!(Parameters);
+
+pub struct Parameters {
+    /// How many rows or columns an empty row or column becomes in part 2.
+    pub multiplier: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            multiplier: 1_000_000,
+        }
+    }
+}
 
 #[derive(PartialEq, Eq)]
-enum What {
+pub enum What {
     Void,
     Galaxy,
 }
 
-struct Galaxy {
-    map: Vec<Vec<What>>,
-    empty_rows: Vec<usize>,
-    empty_columns: Vec<usize>,
+pub struct Galaxy {
+    pub map: Vec<Vec<What>>,
+    pub empty_rows: Vec<usize>,
+    pub empty_columns: Vec<usize>,
 }
 
-fn parse_input(input: &str) -> Galaxy {
+/// Parses the image, and records which rows and columns are empty.
+///
+/// # Errors
+/// When the image is empty, or contains something other than `.` and `#`.
+pub fn parse_input(input: &str) -> Result<Galaxy, SolveError> {
     let mut map = vec![];
-    for line in input.lines() {
+    for (line_index, line) in input.lines().enumerate() {
         let mut row = vec![];
 
-        for c in line.chars() {
+        for (column_index, c) in line.chars().enumerate() {
             let whats_here = match c {
                 '.' => What::Void,
                 '#' => What::Galaxy,
-                _ => panic!("Unrecognized object. UFO?"),
+                _ => {
+                    return Err(SolveError::parse_at(
+                        line_index,
+                        column_index,
+                        "Unrecognized object. UFO?",
+                    ));
+                },
             };
 
             row.push(whats_here);
@@ -33,7 +57,10 @@ fn parse_input(input: &str) -> Galaxy {
     }
 
     let rows = map.len();
-    let columns = map[0].len();
+    let columns = map
+        .first()
+        .map(Vec::len)
+        .ok_or_else(|| SolveError::parse_at(0, 0, "the image is empty"))?;
 
     let rows_with_galaxies: Vec<usize> = (0..rows)
         .fold(vec![false; map.len()], |mut acc, row_index| {
@@ -75,14 +102,15 @@ fn parse_input(input: &str) -> Galaxy {
         )
         .collect();
 
-    Galaxy {
+    Ok(Galaxy {
         map,
         empty_rows: rows_with_galaxies,
         empty_columns: columns_with_galaxies,
-    }
+    })
 }
 
-fn sum_lengths_between_galaxies(galaxy: &Galaxy, multiplier: usize) -> usize {
+#[must_use]
+pub fn sum_lengths_between_galaxies(galaxy: &Galaxy, multiplier: usize) -> usize {
     let mut galaxies = galaxy
         .map
         .iter()
@@ -137,39 +165,42 @@ fn sum_lengths_between_galaxies(galaxy: &Galaxy, multiplier: usize) -> usize {
     sum_of_distances
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let galaxy: Galaxy = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = Galaxy;
 
-        sum_lengths_between_galaxies(&galaxy, 2).into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let galaxy: Galaxy = parse_input(input);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        sum_lengths_between_galaxies(input, 2).into()
+    }
 
-        sum_lengths_between_galaxies(&galaxy, 1_000_000).into()
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        sum_lengths_between_galaxies(input, self.parameters.multiplier).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::shared::solution::read_file;
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
-        use crate::{DAY, Galaxy, parse_input};
+        use crate::days::day11::{Galaxy, PUZZLE, parse_input};
+        use crate::shared::solution::read_file;
+        use crate::{test_example_part_1, test_part_1};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_11), ignore = "no input")]
         fn outcome() {
-            test_part_1!(10_276_166);
+            test_part_1!();
         }
 
         #[test]
         fn example_empty_rows_columns() {
-            let input = read_file("examples", &DAY);
+            let input = read_file("examples", &PUZZLE);
 
-            let galaxy: Galaxy = parse_input(&input);
+            let galaxy: Galaxy = parse_input(&input).unwrap();
 
             assert_eq!(vec![3, 7], galaxy.empty_rows);
             assert_eq!(vec![2, 5, 8], galaxy.empty_columns);
@@ -182,29 +213,40 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::shared::solution::read_file;
-        use advent_of_code_2023::test_part_2;
         use pretty_assertions::assert_eq;
 
-        use crate::{DAY, parse_input, sum_lengths_between_galaxies};
+        use crate::{test_example_part_2, test_part_2};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_11), ignore = "no input")]
         fn outcome() {
-            test_part_2!(598_693_078_798_usize);
+            test_part_2!();
         }
 
         #[test]
         fn example_1() {
-            let galaxy = parse_input(&read_file("examples", &DAY));
-
-            assert_eq!(1030, sum_lengths_between_galaxies(&galaxy, 10));
+            test_example_part_2!(1030, multiplier: 10);
         }
 
         #[test]
         fn example_2() {
-            let galaxy = parse_input(&read_file("examples", &DAY));
+            test_example_part_2!(8410, multiplier: 100);
+        }
+    }
 
-            assert_eq!(8410, sum_lengths_between_galaxies(&galaxy, 100));
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn unrecognized_object() {
+            assert_eq!(
+                Solution::default().try_part_1("#..\n.U.").unwrap_err(),
+                SolveError::parse_at(1, 1, "Unrecognized object. UFO?")
+            );
         }
     }
 }
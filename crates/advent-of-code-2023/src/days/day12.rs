@@ -1,12 +1,14 @@
 use std::fmt::Debug;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::HashMap;
 
-advent_of_code_2023::solution!(7191, 6_512_849_198_636_u64);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
 
 #[derive(Clone, PartialEq, Eq)]
-enum State {
+pub enum State {
     Operational,
     Damaged,
     Unknown,
@@ -35,35 +37,54 @@ impl TryFrom<char> for State {
     }
 }
 
-struct Conditions {
-    rows: Vec<(Vec<State>, Vec<usize>)>,
+#[derive(Clone)]
+pub struct Conditions {
+    pub rows: Vec<(Vec<State>, Vec<usize>)>,
 }
 
-fn parse_input(input: &str) -> Conditions {
-    let rows = input
-        .lines()
-        .map(|l| {
-            let (unparsed_springs, unparsed_contiguous_springs) =
-                l.split_once(' ').expect("Invalid line");
-
-            let springs: Vec<State> = unparsed_springs
-                .chars()
-                .map(|c| c.try_into().unwrap())
-                .collect::<Vec<State>>();
-
-            let contiguous_springs: Vec<usize> = unparsed_contiguous_springs
-                .split(',')
-                .map(|v| v.parse().expect("Invalid number"))
-                .collect();
+/// Parses the springs and the sizes of the groups of damaged springs on every row.
+///
+/// # Errors
+/// When a row is malformed.
+pub fn parse_input(input: &str) -> Result<Conditions, SolveError> {
+    let mut rows = vec![];
+
+    for (line_index, line) in input.lines().enumerate() {
+        let (unparsed_springs, unparsed_contiguous_springs) = line
+            .split_once(' ')
+            .ok_or_else(|| SolveError::parse_at(line_index, 0, "expected `springs groups`"))?;
+
+        let springs = unparsed_springs
+            .chars()
+            .enumerate()
+            .map(|(column_index, c)| {
+                State::try_from(c)
+                    .map_err(|reason| SolveError::parse_at(line_index, column_index, reason))
+            })
+            .collect::<Result<Vec<State>, _>>()?;
+
+        let mut contiguous_springs = vec![];
+        // the groups start after the springs and the space
+        let mut column_index = unparsed_springs.len() + 1;
+
+        for unparsed_group in unparsed_contiguous_springs.split(',') {
+            let group = unparsed_group
+                .parse()
+                .map_err(|_| SolveError::parse_at(line_index, column_index, "Invalid number"))?;
+
+            contiguous_springs.push(group);
+
+            column_index += unparsed_group.len() + 1;
+        }
 
-            (springs, contiguous_springs)
-        })
-        .collect();
+        rows.push((springs, contiguous_springs));
+    }
 
-    Conditions { rows }
+    Ok(Conditions { rows })
 }
 
-fn arrangements(data: &[State], groups: &[usize]) -> usize {
+#[must_use]
+pub fn arrangements(data: &[State], groups: &[usize]) -> usize {
     let mut cache = HashMap::default();
 
     dfs(&mut cache, data, groups, 0)
@@ -186,7 +207,8 @@ fn dfs(
     }
 }
 
-fn sum_all_possibilities(conditions: &Conditions) -> usize {
+#[must_use]
+pub fn sum_all_possibilities(conditions: &Conditions) -> usize {
     let mut sum = 0;
 
     for &(ref places, ref groups) in &conditions.rows {
@@ -196,15 +218,19 @@ fn sum_all_possibilities(conditions: &Conditions) -> usize {
     sum
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let conditions = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = Conditions;
 
-        sum_all_possibilities(&conditions).into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        sum_all_possibilities(input).into()
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let mut conditions = parse_input(input);
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let mut conditions = input.clone();
 
         for &mut (ref mut states, ref mut damaged) in &mut conditions.rows {
             let mut states_repeated = states.clone();
@@ -232,15 +258,16 @@ impl Parts for Solution {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::shared::Parts as _;
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
-        use crate::Solution;
+        use crate::days::day12::Solution;
+        use crate::shared::Parts as _;
+        use crate::{test_example_part_1, test_part_1};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_12), ignore = "no input")]
         fn outcome() {
-            test_part_1!(7191);
+            test_part_1!();
         }
 
         #[test]
@@ -280,12 +307,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_12), ignore = "no input")]
         fn outcome() {
-            test_part_2!(6_512_849_198_636_u64);
+            test_part_2!();
         }
 
         #[test]
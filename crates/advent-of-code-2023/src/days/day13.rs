@@ -0,0 +1,216 @@
+use crate::shared::error::SolveError;
+use crate::shared::grids::GridIter as _;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::view::GridView;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+#[derive(PartialEq, Eq)]
+pub enum What {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for What {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(What::Rock),
+            '.' => Ok(What::Ash),
+            _ => Err("What?"),
+        }
+    }
+}
+
+impl std::fmt::Display for What {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            What::Ash => '.',
+            What::Rock => '#',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+/// Parses the patterns, which are separated by an empty line.
+///
+/// # Errors
+/// When a pattern contains something other than `.` and `#`, or its rows differ in width.
+pub fn parse_input(input: &str) -> Result<Vec<Grid<What>>, SolveError> {
+    let mut all = vec![];
+    // the line of the input each pattern starts at, so errors point at the input, not the pattern
+    let mut first_line = 0;
+
+    for group in input
+        .lines()
+        .collect::<Vec<&str>>()
+        .split(|line| line.is_empty())
+    {
+        all.push(Grid::parse(&group.join("\n")).map_err(|error| error.offset_rows(first_line))?);
+
+        first_line += group.len() + 1;
+    }
+
+    Ok(all)
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Reflection {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+/// Whether the pattern is mirrored over the line above `row_index`, when exactly `smudged` cells
+/// are wrong.
+fn is_mirrored_above(pattern: GridView<'_, What>, row_index: usize, smudged: usize) -> bool {
+    let mut differences = 0;
+
+    for (above, below) in (0..row_index)
+        .rev()
+        .zip(row_index..pattern.get_row_length())
+    {
+        differences += (0..pattern.get_column_length())
+            .filter(|&column_index| {
+                pattern[(above, column_index)] != pattern[(below, column_index)]
+            })
+            .count();
+
+        if differences > smudged {
+            return false;
+        }
+    }
+
+    differences == smudged
+}
+
+/// Finds the row the pattern is mirrored above, when exactly `smudged` cells are wrong.
+fn find_mirrored_row(pattern: GridView<'_, What>, smudged: usize) -> Option<usize> {
+    (1..pattern.get_row_length()).find(|&row_index| is_mirrored_above(pattern, row_index, smudged))
+}
+
+/// Finds the line the pattern is mirrored over when exactly `smudged` cells are wrong, looking at
+/// rows first.
+fn find_smudged_reflection(pattern: &Grid<What>, smudged: usize) -> Option<Reflection> {
+    find_mirrored_row(pattern.view(), smudged)
+        .map(Reflection::Horizontal)
+        .or_else(|| {
+            find_mirrored_row(pattern.view().transpose(), smudged).map(Reflection::Vertical)
+        })
+}
+
+/// Finds the line the pattern is mirrored over.
+///
+/// # Panics
+/// When the pattern has no reflection.
+#[must_use]
+pub fn find_reflection(pattern: &Grid<What>) -> Reflection {
+    find_smudged_reflection(pattern, 0).expect("Shouldn't get here")
+}
+
+/// Finds the line the pattern would be mirrored over when fixing exactly 1 smudge.
+///
+/// # Panics
+/// When there is no such reflection.
+#[must_use]
+pub fn find_reflection_but_not(pattern: &Grid<What>) -> Reflection {
+    find_smudged_reflection(pattern, 1).expect("Shouldn't get here")
+}
+
+#[must_use]
+pub fn add_reflections(patterns: &[Grid<What>]) -> usize {
+    let mut total = 0;
+
+    for pattern in patterns {
+        total += match find_reflection(pattern) {
+            Reflection::Horizontal(v) => v * 100,
+            Reflection::Vertical(v) => v,
+        };
+    }
+
+    total
+}
+
+#[must_use]
+pub fn add_new_reflections(patterns: &[Grid<What>]) -> usize {
+    let mut total = 0;
+
+    for pattern in patterns {
+        total += match find_reflection_but_not(pattern) {
+            Reflection::Horizontal(v) => v * 100,
+            Reflection::Vertical(v) => v,
+        };
+    }
+
+    total
+}
+
+impl ParsedParts for Solution {
+    type Input = Vec<Grid<What>>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        add_reflections(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        add_new_reflections(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_1, test_part_1};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_13), ignore = "no input")]
+        fn outcome() {
+            test_part_1!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_1!(405);
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_2, test_part_2};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_13), ignore = "no input")]
+        fn outcome() {
+            test_part_2!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_2!(400);
+        }
+    }
+
+    mod parse {
+        use pretty_assertions::assert_eq;
+
+        use crate::days::day13::parse_input;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn errors_point_at_the_input() {
+            assert_eq!(
+                parse_input("#.\n.#\n\n#.\n.?\n").err(),
+                Some(SolveError::parse_at(4, 1, "invalid cell `?`: What?"))
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use hashbrown::HashMap;
+
+use crate::shared::error::SolveError;
+use crate::shared::grids::GridIter as _;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::view::GridViewMut;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!(Parameters);
+
+pub struct Parameters {
+    /// How many spin cycles part 2 runs.
+    pub cycles: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Cube,
+    Round,
+    None,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Rock::Cube),
+            'O' => Ok(Rock::Round),
+            '.' => Ok(Rock::None),
+            _ => Err("What?"),
+        }
+    }
+}
+
+impl std::fmt::Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            Rock::Cube => '#',
+            Rock::Round => 'O',
+            Rock::None => '.',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+/// Rolls every round rock north, until it hits a cube, another round rock or the edge.
+///
+/// North is the top of `platform`, so rolling a rotated view rolls the rocks another way.
+pub fn roll_north(platform: &mut GridViewMut<'_, Rock>) {
+    for c in 0..platform.get_column_length() {
+        // where the next round rock in this column comes to rest
+        let mut free_row = 0;
+
+        for r in 0..platform.get_row_length() {
+            match platform[(r, c)] {
+                Rock::Cube => free_row = r + 1,
+                Rock::Round => {
+                    if free_row != r {
+                        platform[(free_row, c)] = Rock::Round;
+                        platform[(r, c)] = Rock::None;
+                    }
+
+                    free_row += 1;
+                },
+                Rock::None => {},
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn count(platform: &Grid<Rock>) -> usize {
+    let mut total = 0;
+
+    for (row_number, row) in platform
+        .rows()
+        .enumerate()
+        .map(|(i, row)| (platform.get_row_length() - i, row))
+    {
+        for column in row {
+            if matches!(*column, Rock::Round) {
+                total += row_number;
+            }
+        }
+    }
+
+    total
+}
+
+#[must_use]
+pub fn roll_north_and_count(mut platform: Grid<Rock>) -> usize {
+    roll_north(&mut platform.view_mut());
+
+    count(&platform)
+}
+
+/// Rolls the rocks north, west, south and east.
+///
+/// Seeing the platform turned clockwise once more for each roll brings the next direction up north.
+pub fn roll_all(platform: &mut Grid<Rock>) {
+    roll_north(&mut platform.view_mut());
+    roll_north(&mut platform.view_mut().rotate_cw());
+    roll_north(&mut platform.view_mut().rotate_cw().rotate_cw());
+    roll_north(&mut platform.view_mut().rotate_ccw());
+}
+
+pub fn as_far_as_we_can(iterations: usize, platform: &mut Grid<Rock>) -> usize {
+    let mut cache = HashMap::<Grid<Rock>, usize>::new();
+
+    for turn in 1..=iterations {
+        if let Some(previous_turn) = cache.get(platform) {
+            // we saw this configuration at `previous_turn`
+            // so we now try to advance as far as possible before exceeding `iterations`
+
+            // how many turns does it take to repeat ourselves
+            let period = turn - previous_turn;
+
+            // given we are at turn `turn`, how many times does our `period` fit
+            return turn + (((iterations - turn) / period) * period);
+        }
+
+        let original = platform.clone();
+
+        roll_all(platform);
+
+        cache.insert(original, turn);
+    }
+
+    // no period found, meaning we actually ran through all iterations, and there is nothing left to do
+    iterations + 1
+}
+
+#[must_use]
+pub fn roll_and_count(cycles: usize, mut platform: Grid<Rock>) -> usize {
+    // we need to establish the amount of turns it takes to go from form A, apply x rolls, and back to A.
+    // we also don't know whether this is at the starting position
+
+    let start = as_far_as_we_can(cycles, &mut platform);
+
+    for _ in start..=cycles {
+        roll_all(&mut platform);
+    }
+
+    count(&platform)
+}
+
+impl ParsedParts for Solution {
+    type Input = Grid<Rock>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        roll_north_and_count(input.clone()).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        roll_and_count(self.parameters.cycles, input.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_1, test_part_1};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_14), ignore = "no input")]
+        fn outcome() {
+            test_part_1!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_1!(136);
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use crate::days::day14::{PUZZLE, count, roll_all, roll_and_count};
+        use crate::shared::grids::grid::Grid;
+        use crate::shared::solution::read_file;
+        use crate::{test_example_part_2, test_part_2};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_14), ignore = "no input")]
+        fn outcome() {
+            test_part_2!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_2!(64);
+        }
+
+        #[test]
+        fn example_few_cycles() {
+            let platform = Grid::parse(&read_file("examples", &PUZZLE)).unwrap();
+            let mut rolled = platform.clone();
+
+            for cycles in 1..=20 {
+                roll_all(&mut rolled);
+
+                assert_eq!(count(&rolled), roll_and_count(cycles, platform.clone()));
+            }
+        }
+    }
+}
@@ -1,18 +1,24 @@
 use std::rc::Rc;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::HashMap;
 use hashbrown::hash_map::Entry;
 
-advent_of_code_2023::solution!(512_797, 262_454);
+use crate::shared::{PartSolution, Parts};
 
-fn parse_input(input: &str) -> Vec<Label> {
+crate::solution!();
+
+pub fn parse_input(input: &str) -> Vec<Label> {
     let parsed = input.trim().split(',').map(Label::new).collect::<Vec<_>>();
 
     parsed
 }
 
-fn parse_input_split(input: &str) -> Vec<(Label, Option<FocalLength>)> {
+/// Parses the steps into their label, and the focal length when it is an `=` operation.
+///
+/// # Panics
+/// When a step is neither an `=` nor a `-` operation, or the focal length is not a number.
+#[must_use]
+pub fn parse_input_split(input: &str) -> Vec<(Label, Option<FocalLength>)> {
     let mut parsed = vec![];
 
     for piece in input.trim().split(',') {
@@ -30,7 +36,12 @@ fn parse_input_split(input: &str) -> Vec<(Label, Option<FocalLength>)> {
     parsed
 }
 
-fn hash_single(s: &str) -> usize {
+/// Runs the HASH algorithm on `s`.
+///
+/// # Panics
+/// When `s` contains a character that doesn't fit in a `usize`.
+#[must_use]
+pub fn hash_single(s: &str) -> usize {
     s.chars()
         .map(|c| usize::try_from(c).unwrap())
         .fold(0, |mut acc, curr| {
@@ -42,16 +53,17 @@ fn hash_single(s: &str) -> usize {
         })
 }
 
-struct Label {
+pub struct Label {
     label: Rc<str>,
     r#box: usize,
 }
 
 #[derive(Clone, Copy)]
-struct FocalLength(usize);
+pub struct FocalLength(usize);
 
 impl FocalLength {
-    fn get_length(self) -> usize {
+    #[must_use]
+    pub fn get_length(self) -> usize {
         self.0
     }
 }
@@ -70,20 +82,24 @@ impl Label {
         }
     }
 
-    fn get_box(&self) -> usize {
+    #[must_use]
+    pub fn get_box(&self) -> usize {
         self.r#box
     }
 
-    fn get_label(&self) -> Rc<str> {
+    #[must_use]
+    pub fn get_label(&self) -> Rc<str> {
         Rc::clone(&self.label)
     }
 }
 
-fn fold_parsed(parsed: &[Label]) -> usize {
+#[must_use]
+pub fn fold_parsed(parsed: &[Label]) -> usize {
     parsed.iter().fold(0, |acc, curr| acc + curr.get_box())
 }
 
-fn put_lenses_in_boxes(parsed: &[(Label, Option<FocalLength>)]) -> usize {
+#[must_use]
+pub fn put_lenses_in_boxes(parsed: &[(Label, Option<FocalLength>)]) -> usize {
     let with_hasher = HashMap::<usize, (HashMap<Rc<str>, (FocalLength, usize)>, usize)>::new();
 
     let mut boxes = with_hasher;
@@ -156,15 +172,16 @@ impl Parts for Solution {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::shared::Parts as _;
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
-        use crate::Solution;
+        use crate::days::day15::Solution;
+        use crate::shared::Parts as _;
+        use crate::{test_example_part_1, test_part_1};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_15), ignore = "no input")]
         fn outcome() {
-            test_part_1!(512_797);
+            test_part_1!();
         }
 
         #[test]
@@ -179,14 +196,15 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
-        use crate::hash_single;
+        use crate::days::day15::hash_single;
+        use crate::{test_example_part_2, test_part_2};
 
         #[test]
+        #[cfg_attr(not(has_input_2023_15), ignore = "no input")]
         fn outcome() {
-            test_part_2!(262_454);
+            test_part_2!();
         }
 
         #[test]
@@ -1,10 +1,13 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
+use crate::shared::error::SolveError;
+use crate::shared::grids::GridIter as _;
+use crate::shared::grids::grid::Grid;
+use crate::shared::{ParsedParts, PartSolution};
 
-advent_of_code_2023::solution!(6902, 7697);
+crate::solution!();
 
 #[derive(Clone, Copy)]
 #[repr(usize)]
-enum Traveling {
+pub enum Traveling {
     Up,
     Right,
     Down,
@@ -22,13 +25,14 @@ impl From<Traveling> for usize {
     }
 }
 
-struct Tile {
+#[derive(Clone)]
+pub struct Tile {
     traveled_from: [bool; 4],
     mirror: Mirror,
 }
 
 #[derive(Clone, Copy)]
-enum Mirror {
+pub enum Mirror {
     Dash,
     Pipe,
     Slash,
@@ -56,7 +60,7 @@ enum Beam {
 }
 
 fn apply_direction_to_coordinates(
-    field: &[Vec<Tile>],
+    field: &Grid<Tile>,
     row_index: usize,
     column_index: usize,
     direction: Traveling,
@@ -65,14 +69,14 @@ fn apply_direction_to_coordinates(
 
     let right = {
         let next_column_index = column_index + 1;
-        (next_column_index < field[row_index].len()).then_some(next_column_index)
+        (next_column_index < field.get_column_length()).then_some(next_column_index)
     };
 
     let up = row_index.checked_sub(1);
 
     let down = {
         let next_row_index = row_index + 1;
-        (next_row_index < field.len()).then_some(next_row_index)
+        (next_row_index < field.get_row_length()).then_some(next_row_index)
     };
 
     match direction {
@@ -152,19 +156,16 @@ impl TryFrom<char> for Mirror {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<Tile>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.try_into().unwrap())
-                .collect::<Vec<Tile>>()
-        })
-        .collect::<Vec<Vec<Tile>>>()
+/// Parses the contraption.
+///
+/// # Errors
+/// When the contraption is empty, its rows differ in width, or it contains an unknown tile.
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, SolveError> {
+    Ok(Grid::parse(input)?)
 }
 
-fn send_light(
-    parsed: &mut [Vec<Tile>],
+pub fn send_light(
+    parsed: &mut Grid<Tile>,
     mut row_index: usize,
     mut column_index: usize,
     mut direction: Traveling,
@@ -205,31 +206,29 @@ fn send_light(
     }
 }
 
-fn count_energized(parsed: &[Vec<Tile>]) -> usize {
+#[must_use]
+pub fn count_energized(parsed: &Grid<Tile>) -> usize {
     let mut count: usize = 0;
-    for row in parsed {
-        for tile in row {
-            if tile.traveled_from.iter().any(|a| *a) {
-                count += 1;
-            }
+    for tile in parsed.cells() {
+        if tile.traveled_from.iter().any(|a| *a) {
+            count += 1;
         }
     }
     count
 }
 
-fn reset(parsed: &mut Vec<Vec<Tile>>) {
-    for row in parsed {
-        for tile in row {
-            tile.reset();
-        }
+fn reset(parsed: &mut Grid<Tile>) {
+    for tile in parsed.cells_mut() {
+        tile.reset();
     }
 }
 
-fn find_highest_entrypoint(mut parsed: Vec<Vec<Tile>>) -> usize {
+#[must_use]
+pub fn find_highest_entrypoint(mut parsed: Grid<Tile>) -> usize {
     let mut highest = usize::MIN;
 
     {
-        for row_index in 0..parsed.len() {
+        for row_index in 0..parsed.get_row_length() {
             // now we do column 0 going right
 
             // we start from (0,-1), going down, to (0,0)
@@ -241,8 +240,8 @@ fn find_highest_entrypoint(mut parsed: Vec<Vec<Tile>>) -> usize {
     }
 
     {
-        let last_column_index = parsed[0].len() - 1;
-        for row_index in 0..parsed.len() {
+        let last_column_index = parsed.get_column_length() - 1;
+        for row_index in 0..parsed.get_row_length() {
             // now we do column 0 going right
 
             // we start from (0,-1), going down, to (0,0)
@@ -254,7 +253,7 @@ fn find_highest_entrypoint(mut parsed: Vec<Vec<Tile>>) -> usize {
     }
 
     {
-        for column_index in 0..parsed[0].len() {
+        for column_index in 0..parsed.get_column_length() {
             // now we do column 0 going right
 
             // we start from (0,-1), going down, to (0,0)
@@ -266,8 +265,8 @@ fn find_highest_entrypoint(mut parsed: Vec<Vec<Tile>>) -> usize {
     }
 
     {
-        let last_row_index = parsed[0].len() - 1;
-        for column_index in 0..parsed[last_row_index].len() {
+        let last_row_index = parsed.get_row_length() - 1;
+        for column_index in 0..parsed.get_column_length() {
             // now we do column 0 going right
 
             // we start from (0,-1), going down, to (0,0)
@@ -281,32 +280,38 @@ fn find_highest_entrypoint(mut parsed: Vec<Vec<Tile>>) -> usize {
     highest
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let mut parsed = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let mut contraption = input.clone();
 
         // we start from (0,-1), going right, to (0,0)
-        send_light(&mut parsed, 0, 0, Traveling::Right);
+        send_light(&mut contraption, 0, 0, Traveling::Right);
 
-        count_energized(&parsed).into()
+        count_energized(&contraption).into()
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let parsed = parse_input(input);
-
-        find_highest_entrypoint(parsed).into()
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        find_highest_entrypoint(input.clone()).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_16), ignore = "no input")]
         fn outcome() {
-            test_part_1!(6902);
+            test_part_1!();
         }
 
         #[test]
@@ -316,12 +321,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_16), ignore = "no input")]
         fn outcome() {
-            test_part_2!(7697);
+            test_part_2!();
         }
 
         #[test]
@@ -329,4 +336,20 @@ mod test {
             test_example_part_2!(51);
         }
     }
+
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn invalid_character() {
+            assert_eq!(
+                Solution {}.try_part_1(".|.\n.x.").unwrap_err(),
+                SolveError::parse_at(1, 1, "invalid cell `x`: Invalid character")
+            );
+        }
+    }
 }
@@ -1,21 +1,22 @@
-use std::cell::Cell;
 use std::collections::BinaryHeap;
 use std::ops::RangeInclusive;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::HashMap;
 
-advent_of_code_2023::solution!(928, 1104);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
 
-struct Block {
-    value: u32,
-    visited: Cell<bool>,
+crate::solution!();
+
+#[derive(Clone)]
+pub struct Block {
+    pub value: u32,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-struct Coordinates {
-    row_index: usize,
-    column_index: usize,
+pub struct Coordinates {
+    pub row_index: usize,
+    pub column_index: usize,
 }
 
 impl From<(usize, usize)> for Coordinates {
@@ -90,29 +91,31 @@ fn get_neighbor_directions<T>(city_map: &[Vec<T>], node: &Node) -> Vec<(Coordina
     neighbors
 }
 
-fn parse_lines(lines: &[&str]) -> Vec<Vec<Block>> {
+/// Parses the heat loss of every block.
+///
+/// # Errors
+/// When a block is not a single digit.
+pub fn parse_lines(lines: &[&str]) -> Result<Vec<Vec<Block>>, SolveError> {
     let mut map = Vec::new();
 
-    for line in lines {
+    for (line_index, line) in lines.iter().enumerate() {
         map.push(
             line.chars()
-                .map(|x| x.to_digit(10).unwrap())
-                .map(|x| Block {
-                    value: x,
-                    visited: Cell::new(false),
+                .enumerate()
+                .map(|(column_index, x)| {
+                    x.to_digit(10).ok_or_else(|| {
+                        SolveError::parse_at(line_index, column_index, "expected a digit")
+                    })
                 })
-                .collect(),
+                .map(|x| x.map(|value| Block { value }))
+                .collect::<Result<_, _>>()?,
         );
     }
 
-    map
+    Ok(map)
 }
 
-fn reconstruct_path(
-    map: &[Vec<Block>],
-    mut came_from: HashMap<Node, Node>,
-    mut current: Node,
-) -> Vec<Coordinates> {
+fn reconstruct_path(mut came_from: HashMap<Node, Node>, mut current: Node) -> Vec<Coordinates> {
     let mut total_path = vec![current.row_column_index];
 
     while let Some(next) = came_from.remove(&current) {
@@ -121,10 +124,6 @@ fn reconstruct_path(
         current = next;
     }
 
-    for p in &total_path {
-        map[p.row_index][p.column_index].visited.set(true);
-    }
-
     total_path.reverse();
     total_path
 }
@@ -188,12 +187,19 @@ impl PartialOrd for Node {
     }
 }
 
-fn a_star(
-    map: &mut [Vec<Block>],
+/// Finds the path with the least heat loss, moving between `min_max` blocks in one direction before turning.
+///
+/// Returns [`None`] when `goal` cannot be reached.
+///
+/// # Panics
+/// When `start` lies outside of `map`.
+#[must_use]
+pub fn a_star(
+    map: &[Vec<Block>],
     start: Coordinates,
     goal: Coordinates,
     min_max: RangeInclusive<u32>,
-) -> Vec<Coordinates> {
+) -> Option<Vec<Coordinates>> {
     let start = Node {
         row_column_index: start,
         previous_direction: Direction::Up,
@@ -212,7 +218,7 @@ fn a_star(
 
     while let Some(node) = open_set.pop() {
         if node.row_column_index == goal {
-            return reconstruct_path(map, came_from, node);
+            return Some(reconstruct_path(came_from, node));
         }
 
         let neighbors = get_neighbor_directions(map, &node);
@@ -252,86 +258,68 @@ fn a_star(
         }
     }
 
-    panic!("No solution found")
+    None
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let lines: Vec<&str> = input.lines().collect();
-
-        let mut parsed = parse_lines(&lines);
-
-        let max_row = parsed.len() - 1;
-        let max_col = parsed[0].len() - 1;
+/// Sums the heat loss on the cheapest path from the top-left to the bottom-right block.
+fn least_heat_loss(map: &[Vec<Block>], min_max: RangeInclusive<u32>) -> Result<u32, SolveError> {
+    let (Some(max_row), Some(max_col)) = (
+        map.len().checked_sub(1),
+        map.first().and_then(|row| row.len().checked_sub(1)),
+    ) else {
+        return Err(SolveError::no_solution("the map is empty"));
+    };
 
-        let cheapest = a_star(&mut parsed, (0, 0).into(), (max_row, max_col).into(), 0..=3);
+    let cheapest = a_star(map, (0, 0).into(), (max_row, max_col).into(), min_max)
+        .ok_or_else(|| SolveError::no_solution("the bottom-right block cannot be reached"))?;
+
+    Ok(cheapest
+        .iter()
+        .skip(1)
+        .map(
+            |&Coordinates {
+                 row_index: r,
+                 column_index: c,
+             }| (map[r][c]).value,
+        )
+        .sum::<u32>())
+}
 
-        dump_map(&parsed);
+impl ParsedParts for Solution {
+    type Input = Vec<Vec<Block>>;
 
-        cheapest
-            .iter()
-            .skip(1)
-            .map(
-                |&Coordinates {
-                     row_index: r,
-                     column_index: c,
-                 }| (parsed[r][c]).value,
-            )
-            .sum::<u32>()
-            .into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(&input.lines().collect::<Vec<_>>())
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let lines: Vec<&str> = input.lines().collect();
-
-        let mut parsed = parse_lines(&lines);
-
-        let max_row = parsed.len() - 1;
-        let max_col = parsed[0].len() - 1;
-
-        let cheapest = a_star(
-            &mut parsed,
-            (0, 0).into(),
-            (max_row, max_col).into(),
-            4..=10,
-        );
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        ParsedParts::try_part_1(self, input).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        dump_map(&parsed);
-
-        cheapest
-            .iter()
-            .skip(1)
-            .map(
-                |&Coordinates {
-                     row_index: r,
-                     column_index: c,
-                 }| (parsed[r][c]).value,
-            )
-            .sum::<u32>()
-            .into()
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        ParsedParts::try_part_2(self, input).unwrap_or_else(|error| panic!("{}", error))
     }
-}
 
-fn dump_map(map: &[Vec<Block>]) {
-    for r in map {
-        for c in r {
-            let color: u32 = if c.visited.get() { 31 } else { 0 };
-            print!("\x1b[{}m{}\x1b[0m", color, c.value);
-        }
+    fn try_part_1(&self, input: &Self::Input) -> Result<PartSolution, SolveError> {
+        least_heat_loss(input, 0..=3).map(Into::into)
+    }
 
-        println!();
+    fn try_part_2(&self, input: &Self::Input) -> Result<PartSolution, SolveError> {
+        least_heat_loss(input, 4..=10).map(Into::into)
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_17), ignore = "no input")]
         fn outcome() {
-            test_part_1!(928);
+            test_part_1!();
         }
 
         #[test]
@@ -341,12 +329,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_17), ignore = "no input")]
         fn outcome() {
-            test_part_2!(1104);
+            test_part_2!();
         }
 
         #[test]
@@ -354,4 +344,28 @@ mod test {
             test_example_part_2!(94);
         }
     }
+
+    mod try_part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn not_a_digit() {
+            assert_eq!(
+                Solution {}.try_part_1("12\n3x").unwrap_err(),
+                SolveError::parse_at(1, 1, "expected a digit")
+            );
+        }
+
+        #[test]
+        fn empty_map() {
+            assert_eq!(
+                Solution {}.try_part_1("").unwrap_err(),
+                SolveError::no_solution("the map is empty")
+            );
+        }
+    }
 }
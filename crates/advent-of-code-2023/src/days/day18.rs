@@ -2,10 +2,12 @@
 
 use std::sync::LazyLock;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use regex::{Regex, RegexBuilder};
 
-advent_of_code_2023::solution!(40714, 129_849_166_997_110_usize);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
 
 static REGEX: LazyLock<Regex> = LazyLock::new(|| {
     let mut builder =
@@ -16,19 +18,19 @@ static REGEX: LazyLock<Regex> = LazyLock::new(|| {
     builder.build().unwrap()
 });
 
-enum Direction {
+pub enum Direction {
     Up,
     Right,
     Down,
     Left,
 }
 
-struct Instruction {
+pub struct Instruction {
     part1: InstructionPart,
     part2: InstructionPart,
 }
 
-struct InstructionPart {
+pub struct InstructionPart {
     direction: Direction,
     count: usize,
 }
@@ -67,34 +69,57 @@ impl TryFrom<u8> for Direction {
     }
 }
 
-fn parse_lines(input: &str) -> Vec<Instruction> {
+/// Parses the dig plan, both as written and as decoded from the color.
+///
+/// # Errors
+/// When an instruction is malformed.
+///
+/// # Panics
+/// When the regex matched, but is missing one of its groups.
+pub fn parse_lines(input: &str) -> Result<Vec<Instruction>, SolveError> {
     let mut instructions = vec![];
 
-    for line in input.lines() {
-        let captures = REGEX.captures(line).unwrap();
+    for (line_index, line) in input.lines().enumerate() {
+        let captures = REGEX.captures(line).ok_or_else(|| {
+            SolveError::parse_at(line_index, 0, "expected `direction count (#color)`")
+        })?;
+
+        let direction = captures.name("direction").unwrap();
 
-        let part1_direction: Direction = captures
-            .name("direction")
-            .unwrap()
+        let part1_direction: Direction = direction
             .as_str()
             .chars()
             .next()
-            .map(|c| c.try_into().expect("Couldn't convert to direction"))
-            .expect("No character found?");
-
-        let part1_count = captures
-            .name("count")
-            .unwrap()
+            .and_then(|c| c.try_into().ok())
+            .ok_or_else(|| {
+                SolveError::parse_at(
+                    line_index,
+                    direction.start(),
+                    "Couldn't convert to direction",
+                )
+            })?;
+
+        let count = captures.name("count").unwrap();
+
+        let part1_count = count
             .as_str()
             .parse()
-            .expect("Invalid count");
+            .map_err(|_| SolveError::parse_at(line_index, count.start(), "Invalid count"))?;
 
-        let part2 = captures.name("color").unwrap().as_str();
+        let color = captures.name("color").unwrap();
+        let part2 = color.as_str();
 
+        // the regex guarantees 6 hex digits, but only 0 through 3 are directions
         let part2_direction: Direction = u8::from_str_radix(&part2[5..], 16)
-            .unwrap()
-            .try_into()
-            .unwrap();
+            .ok()
+            .and_then(|d| d.try_into().ok())
+            .ok_or_else(|| {
+                SolveError::parse_at(
+                    line_index,
+                    color.start() + 5,
+                    "Couldn't convert to direction",
+                )
+            })?;
 
         let part2_count = usize::from_str_radix(&part2[0..5], 16).unwrap();
 
@@ -117,7 +142,7 @@ fn parse_lines(input: &str) -> Vec<Instruction> {
         });
     }
 
-    instructions
+    Ok(instructions)
 }
 
 fn get_lagoon_specs(instructions: &[&InstructionPart]) -> Coordinates {
@@ -150,7 +175,7 @@ fn get_lagoon_specs(instructions: &[&InstructionPart]) -> Coordinates {
     }
 }
 
-pub(crate) fn build_coordinates(
+fn build_coordinates(
     start_coordinates: Coordinates,
     instructions: &[&InstructionPart],
 ) -> (Vec<Coordinates>, usize) {
@@ -175,7 +200,7 @@ pub(crate) fn build_coordinates(
     (coordinates, perimeter)
 }
 
-pub(crate) fn shoelace(coordinates: &[Coordinates], perimeter: usize) -> usize {
+fn shoelace(coordinates: &[Coordinates], perimeter: usize) -> usize {
     let mut size: isize = 0;
 
     for coord in coordinates.array_windows::<2>() {
@@ -193,7 +218,8 @@ pub(crate) fn shoelace(coordinates: &[Coordinates], perimeter: usize) -> usize {
     TryInto::<usize>::try_into((size / 2).abs()).unwrap() + (perimeter / 2) + 1
 }
 
-fn dig_pool(instructions: &[&InstructionPart]) -> usize {
+#[must_use]
+pub fn dig_pool(instructions: &[&InstructionPart]) -> usize {
     let start_coordinates = get_lagoon_specs(instructions);
 
     let (coordinates, edges) = build_coordinates(start_coordinates, instructions);
@@ -201,19 +227,21 @@ fn dig_pool(instructions: &[&InstructionPart]) -> usize {
     shoelace(&coordinates, edges)
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
+impl ParsedParts for Solution {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
 
-        let instructions = parsed.iter().map(|i| &i.part1).collect::<Vec<_>>();
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let instructions = input.iter().map(|i| &i.part1).collect::<Vec<_>>();
 
         dig_pool(&instructions).into()
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let parsed = parse_lines(input);
-
-        let instructions = parsed.iter().map(|i| &i.part2).collect::<Vec<_>>();
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let instructions = input.iter().map(|i| &i.part2).collect::<Vec<_>>();
 
         dig_pool(&instructions).into()
     }
@@ -222,12 +250,14 @@ impl Parts for Solution {
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_18), ignore = "no input")]
         fn outcome() {
-            test_part_1!(40714);
+            test_part_1!();
         }
 
         #[test]
@@ -242,12 +272,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_18), ignore = "no input")]
         fn outcome() {
-            test_part_2!(129_849_166_997_110_usize);
+            test_part_2!();
         }
 
         #[test]
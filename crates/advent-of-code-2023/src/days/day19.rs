@@ -1,8 +1,10 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::HashMap;
 use regex::Regex;
 
-advent_of_code_2023::solution!(420_739, 130_251_901_420_382_usize);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
 
 use std::sync::LazyLock;
 
@@ -16,7 +18,7 @@ static WORKFLOW_REGEX_RULE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new("(?<property>[xmas])(?<cmp>[<>])(?<value>[0-9]*):(?<target>[AR]|[a-z]*)").unwrap()
 });
 
-struct Part {
+pub struct Part {
     // x: Extremely cool looking
     // m: Musical (it makes a noise when you hit it)
     // a: Aerodynamic
@@ -27,7 +29,7 @@ struct Part {
     s: usize,
 }
 
-struct Workflow {
+pub struct Workflow {
     name: String,
     rules: Vec<Rule>,
 }
@@ -44,7 +46,7 @@ impl Workflow {
     }
 }
 
-enum Rule {
+pub enum Rule {
     Lt(Property, usize, Next),
     Gt(Property, usize, Next),
     Next(Next),
@@ -72,7 +74,7 @@ impl Rule {
     }
 }
 
-enum Property {
+pub enum Property {
     X,
     M,
     A,
@@ -116,34 +118,49 @@ impl TryFrom<&str> for Property {
 }
 
 #[derive(Clone)]
-enum Next {
+pub enum Next {
     Accept,
     Reject,
     Named(String),
 }
 
-fn parse_input(input: &str) -> (HashMap<String, Workflow>, Vec<Part>) {
+/// Parses the workflows and the parts, which are separated by an empty line.
+///
+/// # Errors
+/// When the input is malformed.
+pub fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), SolveError> {
     let Some((unparsed_workflows, unparsed_parts)) = input.split_once("\n\n") else {
-        panic!("invalid input");
+        return Err(SolveError::parse_at(
+            0,
+            0,
+            "expected the workflows and the parts, separated by an empty line",
+        ));
     };
 
     let mut workflows = HashMap::<String, Workflow>::new();
     let mut parts = vec![];
 
-    for unparsed_workflow in unparsed_workflows.lines() {
-        let workflow = parse_workflow(unparsed_workflow);
+    for (line_index, unparsed_workflow) in unparsed_workflows.lines().enumerate() {
+        let workflow = parse_workflow(unparsed_workflow)
+            .map_err(|reason| SolveError::parse_at(line_index, 0, reason))?;
 
         workflows.insert(workflow.name.clone(), workflow);
     }
 
-    for unparsed_part in unparsed_parts.lines() {
-        parts.push(parse_part(unparsed_part));
+    // the parts start after the workflows and the empty line
+    let first_part_line = unparsed_workflows.lines().count() + 1;
+
+    for (index, unparsed_part) in unparsed_parts.lines().enumerate() {
+        let part = parse_part(unparsed_part)
+            .map_err(|reason| SolveError::parse_at(first_part_line + index, 0, reason))?;
+
+        parts.push(part);
     }
 
-    (workflows, parts)
+    Ok((workflows, parts))
 }
 
-fn parse_workflow(unparsed_workflow: &str) -> Workflow {
+fn parse_workflow(unparsed_workflow: &str) -> Result<Workflow, &'static str> {
     // BNF
     // <start> ::= "in"
     // <name> ::= <start> | <identifier>
@@ -161,19 +178,21 @@ fn parse_workflow(unparsed_workflow: &str) -> Workflow {
 
     // but we're lazy, so we're going to do regex
 
-    let captures = WORKFLOW_REGEX.captures(unparsed_workflow).unwrap();
+    let captures = WORKFLOW_REGEX
+        .captures(unparsed_workflow)
+        .ok_or("expected `name{rules}`")?;
 
     let name = captures.name("name").unwrap().as_str();
 
     let mut rules = vec![];
     for unparsed_rule in captures.name("pieces").unwrap().as_str().split(',') {
-        rules.push(parse_rule(unparsed_rule));
+        rules.push(parse_rule(unparsed_rule)?);
     }
 
-    Workflow {
+    Ok(Workflow {
         name: name.into(),
         rules,
-    }
+    })
 }
 
 fn parse_rule_name(unparsed_rule_name: &str) -> Next {
@@ -184,41 +203,57 @@ fn parse_rule_name(unparsed_rule_name: &str) -> Next {
     }
 }
 
-fn parse_rule(unparsed_rule: &str) -> Rule {
+fn parse_rule(unparsed_rule: &str) -> Result<Rule, &'static str> {
     if let Some(captures) = WORKFLOW_REGEX_RULE.captures(unparsed_rule) {
-        let property = captures
-            .name("property")
+        let property = captures.name("property").unwrap().as_str().try_into()?;
+
+        let value: usize = captures
+            .name("value")
             .unwrap()
             .as_str()
-            .try_into()
-            .unwrap();
-
-        let value: usize = captures.name("value").unwrap().as_str().parse().unwrap();
+            .parse()
+            .map_err(|_| "Invalid value")?;
 
         let target = parse_rule_name(captures.name("target").unwrap().as_str());
 
         match captures.name("cmp").unwrap().as_str() {
-            "<" => Rule::Lt(property, value, target),
-            ">" => Rule::Gt(property, value, target),
-            _ => panic!("Invalid cmp"),
+            "<" => Ok(Rule::Lt(property, value, target)),
+            ">" => Ok(Rule::Gt(property, value, target)),
+            _ => Err("Invalid cmp"),
         }
     } else {
-        Rule::Next(parse_rule_name(unparsed_rule))
+        Ok(Rule::Next(parse_rule_name(unparsed_rule)))
     }
 }
 
-fn parse_part(unparsed_part: &str) -> Part {
-    let captures = PART_REGEX.captures(unparsed_part).unwrap();
+fn parse_part(unparsed_part: &str) -> Result<Part, &'static str> {
+    let captures = PART_REGEX
+        .captures(unparsed_part)
+        .ok_or("expected `{x=..,m=..,a=..,s=..}`")?;
 
-    Part {
-        x: captures.name("x").unwrap().as_str().parse().unwrap(),
-        m: captures.name("m").unwrap().as_str().parse().unwrap(),
-        a: captures.name("a").unwrap().as_str().parse().unwrap(),
-        s: captures.name("s").unwrap().as_str().parse().unwrap(),
-    }
+    let rating = |name| {
+        captures
+            .name(name)
+            .unwrap()
+            .as_str()
+            .parse()
+            .map_err(|_| "Invalid rating")
+    };
+
+    Ok(Part {
+        x: rating("x")?,
+        m: rating("m")?,
+        a: rating("a")?,
+        s: rating("s")?,
+    })
 }
 
-fn process_part(start: &Workflow, workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
+/// Sends the part through the workflows, starting at `start`, returning whether it was accepted.
+///
+/// # Panics
+/// When a rule sends the part to a workflow that doesn't exist.
+#[must_use]
+pub fn process_part(start: &Workflow, workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
     let mut current = start;
 
     loop {
@@ -240,15 +275,20 @@ fn process_part(start: &Workflow, workflows: &HashMap<String, Workflow>, part: &
     }
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let (workflows, parts) = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
 
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let &(ref workflows, ref parts) = input;
         let mut sum = 0;
         let start = workflows.get("in").expect("Couldn't find the 'in' start");
 
         for p in parts {
-            if process_part(start, &workflows, &p) {
+            if process_part(start, workflows, p) {
                 sum += p.x + p.m + p.a + p.s;
             }
         }
@@ -256,28 +296,26 @@ impl Parts for Solution {
         sum.into()
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let (workflows, _) = parse_input(input);
-
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let &(ref workflows, _) = input;
         let start = workflows.get("in").expect("Couldn't find the 'in' start");
 
         workflow_recursive(
             start,
-            &workflows,
+            workflows,
             Limits {
                 x: (0, 4001),
                 m: (0, 4001),
                 a: (0, 4001),
                 s: (0, 4001),
             },
-            0,
         )
         .into()
     }
 }
 
 #[derive(Clone)]
-struct Limits {
+pub struct Limits {
     x: (usize, usize),
     m: (usize, usize),
     a: (usize, usize),
@@ -315,30 +353,19 @@ impl Limits {
     }
 }
 
-fn next_recursive(
-    next: &Next,
-    workflows: &HashMap<String, Workflow>,
-    limits: Limits,
-    arg: usize,
-) -> usize {
-    let padding = std::iter::repeat_n(' ', arg).collect::<String>();
+fn next_recursive(next: &Next, workflows: &HashMap<String, Workflow>, limits: Limits) -> usize {
     match *next {
         Next::Accept => {
-            println!("{}GOOD: {}", padding, limits);
-
             (limits.x.0 + 1..limits.x.1).len()
                 * (limits.m.0 + 1..limits.m.1).len()
                 * (limits.a.0 + 1..limits.a.1).len()
                 * (limits.s.0 + 1..limits.s.1).len()
         },
-        Next::Reject => {
-            println!("{}IGNORE", padding);
-            0
-        },
+        Next::Reject => 0,
         Next::Named(ref name) => {
             let wf = workflows.get(name).expect("bad cache");
 
-            workflow_recursive(wf, workflows, limits, arg)
+            workflow_recursive(wf, workflows, limits)
         },
     }
 }
@@ -347,36 +374,29 @@ fn workflow_recursive(
     current: &Workflow,
     workflows: &HashMap<String, Workflow>,
     mut limits: Limits,
-    arg: usize,
 ) -> usize {
     let mut sums = 0;
 
-    let padding = std::iter::repeat_n(' ', arg).collect::<String>();
-
     for rule in &current.rules {
         match *rule {
             Rule::Lt(ref p, v, ref n) => {
                 let mut clone = limits.clone();
 
-                println!("{}{}<{}", padding, p, v);
-
                 clone.set_less(p, v);
                 limits.set_more(p, v - 1);
 
-                sums += next_recursive(n, workflows, clone, arg + 4);
+                sums += next_recursive(n, workflows, clone);
             },
             Rule::Gt(ref p, v, ref n) => {
                 let mut clone = limits.clone();
 
-                println!("{}{}>{}", padding, p, v);
-
                 clone.set_more(p, v);
                 limits.set_less(p, v + 1);
 
-                sums += next_recursive(n, workflows, clone, arg + 4);
+                sums += next_recursive(n, workflows, clone);
             },
             Rule::Next(ref n) => {
-                sums += next_recursive(n, workflows, limits.clone(), arg);
+                sums += next_recursive(n, workflows, limits.clone());
             },
         }
     }
@@ -387,12 +407,14 @@ fn workflow_recursive(
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_19), ignore = "no input")]
         fn outcome() {
-            test_part_1!(420_739);
+            test_part_1!();
         }
 
         #[test]
@@ -402,12 +424,14 @@ mod test {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_19), ignore = "no input")]
         fn outcome() {
-            test_part_2!(130_251_901_420_382_usize);
+            test_part_2!();
         }
 
         #[test]
@@ -1,14 +1,27 @@
 use std::collections::VecDeque;
 
-use advent_of_code_2023::shared::{PartSolution, Parts};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
-advent_of_code_2023::solution!(763_500_168, 207_652_583_562_007_usize);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!(Parameters);
+
+pub struct Parameters {
+    /// How often part 1 pushes the button.
+    pub presses: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self { presses: 1000 }
+    }
+}
 
 const BROADCASTER: &str = "broadcaster";
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum Kind {
+pub enum Kind {
     FlipFlop(FlipFlop),
     Conjunction(Conjunction),
     Broadcaster(Broadcaster),
@@ -21,10 +34,10 @@ enum State {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Broadcaster {}
+pub struct Broadcaster {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Conjunction {
+pub struct Conjunction {
     sources: HashMap<String, Pulse>,
 }
 
@@ -38,12 +51,12 @@ impl std::hash::Hash for Conjunction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct FlipFlop {
+pub struct FlipFlop {
     state: State,
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
-enum Pulse {
+pub enum Pulse {
     High,
     Low,
 }
@@ -175,38 +188,55 @@ impl TModule for FlipFlop {
 fn to_module(
     unparsed_module_name: &str,
     unparsed_destinations: &str,
-) -> (String, (Kind, Vec<String>)) {
+) -> Option<(String, (Kind, Vec<String>))> {
     let destinations = unparsed_destinations
         .split(", ")
         .map(str::to_owned)
         .collect::<Vec<String>>();
 
     if unparsed_module_name == BROADCASTER {
-        (
+        return Some((
             String::from(BROADCASTER),
             (Kind::Broadcaster(Broadcaster::new()), destinations),
-        )
-    } else if let Some(stripped) = unparsed_module_name.strip_prefix('%') {
-        (
+        ));
+    }
+
+    if let Some(stripped) = unparsed_module_name.strip_prefix('%') {
+        return Some((
             String::from(stripped),
             (Kind::FlipFlop(FlipFlop::new()), destinations),
-        )
-    } else if let Some(stripped) = unparsed_module_name.strip_prefix('&') {
+        ));
+    }
+
+    unparsed_module_name.strip_prefix('&').map(|stripped| {
         (
             String::from(stripped),
             (Kind::Conjunction(Conjunction::new()), destinations),
         )
-    } else {
-        panic!("not recognized")
-    }
+    })
 }
 
-fn parse_input(input: &str) -> HashMap<String, (Kind, Vec<String>)> {
+/// Parses the modules, and connects every conjunction to its inputs.
+///
+/// # Errors
+/// When a module is malformed.
+///
+/// # Panics
+/// When a conjunction disappears while connecting it.
+pub fn parse_input(input: &str) -> Result<HashMap<String, (Kind, Vec<String>)>, SolveError> {
     let mut modules = HashMap::new();
 
-    for line in input.lines() {
-        let (unparsed_module_name, unparsed_destinations) = line.split_once(" -> ").unwrap();
-        let (module_name, module) = to_module(unparsed_module_name, unparsed_destinations);
+    for (line_index, line) in input.lines().enumerate() {
+        let (unparsed_module_name, unparsed_destinations) =
+            line.split_once(" -> ").ok_or_else(|| {
+                SolveError::parse_at(line_index, 0, "expected `name -> destinations`")
+            })?;
+
+        let (module_name, module) = to_module(unparsed_module_name, unparsed_destinations)
+            .ok_or_else(|| {
+                SolveError::parse_at(line_index, 0, "expected `broadcaster`, `%name` or `&name`")
+            })?;
+
         modules.insert(module_name, module);
     }
 
@@ -245,7 +275,7 @@ fn parse_input(input: &str) -> HashMap<String, (Kind, Vec<String>)> {
         }
     }
 
-    modules
+    Ok(modules)
 }
 
 fn handle_signals(
@@ -278,10 +308,12 @@ fn handle_signals(
     signals
 }
 
-fn press_button_1000(mut modules: HashMap<String, (Kind, Vec<String>)>) -> usize {
+/// Multiplies the low pulses by the high pulses sent over `presses` button presses.
+#[must_use]
+pub fn press_button(presses: usize, mut modules: HashMap<String, (Kind, Vec<String>)>) -> usize {
     let (mut low, mut high) = (0, 0);
 
-    for _ in 1_usize..=1000 {
+    for _ in 1..=presses {
         let mut signals = VecDeque::from_iter([(
             String::from("button"),
             String::from(BROADCASTER),
@@ -306,17 +338,42 @@ fn press_button_1000(mut modules: HashMap<String, (Kind, Vec<String>)>) -> usize
     low * high
 }
 
-fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> usize {
-    let rx_feeder = modules
-        .iter()
-        .find_map(|(n, &(_, ref d))| {
-            if d.contains(&"rx".into()) {
-                Some(n.clone())
-            } else {
-                None
-            }
-        })
-        .unwrap();
+/// The state of every module, in the order of `names`: whether each flip-flop is on, and whether
+/// each conjunction remembers a high pulse from each of its sources.
+fn snapshot(modules: &HashMap<String, (Kind, Vec<String>)>, names: &[String]) -> Vec<bool> {
+    let mut state = vec![];
+
+    for name in names {
+        match modules.get(name) {
+            Some(&(Kind::FlipFlop(ref flip_flop), _)) => state.push(flip_flop.state == State::On),
+            Some(&(Kind::Conjunction(ref conjunction), _)) => {
+                let mut sources = conjunction.sources.iter().collect::<Vec<_>>();
+
+                sources.sort_unstable_by_key(|&(source, _)| source);
+
+                state.extend(sources.into_iter().map(|(_, &pulse)| pulse == Pulse::High));
+            },
+            Some(&(Kind::Broadcaster(_), _)) | None => {},
+        }
+    }
+
+    state
+}
+
+/// Counts the button presses until `rx` receives a single low pulse.
+///
+/// Returns [`None`] when no module sends pulses to `rx`, or when the modules are back in a state
+/// they were in before every input of `rx`'s feeder sent it a high pulse, as they would then only
+/// repeat themselves.
+#[must_use]
+pub fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> Option<usize> {
+    let rx_feeder = modules.iter().find_map(|(n, &(_, ref d))| {
+        if d.contains(&"rx".into()) {
+            Some(n.clone())
+        } else {
+            None
+        }
+    })?;
 
     let mut visited = modules
         .iter()
@@ -331,6 +388,12 @@ fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> us
 
     let mut lengths = HashMap::new();
 
+    let mut names = modules.keys().cloned().collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut seen = HashSet::new();
+    seen.insert(snapshot(&modules, &names));
+
     for i in 1.. {
         let mut signals = VecDeque::from_iter([(
             String::from("button"),
@@ -340,7 +403,9 @@ fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> us
 
         while let Some((source, destination, pulse)) = signals.pop_front() {
             if destination == rx_feeder && pulse == Pulse::High {
-                *visited.get_mut(&source).unwrap() += 1;
+                if let Some(count) = visited.get_mut(&source) {
+                    *count += 1;
+                }
 
                 if !lengths.contains_key(&source) {
                     lengths.insert(source.clone(), i);
@@ -353,7 +418,7 @@ fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> us
                         product *= l;
                     }
 
-                    return product;
+                    return Some(product);
                 }
             }
 
@@ -363,34 +428,48 @@ fn press_button_forever(mut modules: HashMap<String, (Kind, Vec<String>)>) -> us
                 signals.push_back((source, destination, pulse));
             }
         }
+
+        if !seen.insert(snapshot(&modules, &names)) {
+            return None;
+        }
     }
 
-    panic!()
+    None
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let modules = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = HashMap<String, (Kind, Vec<String>)>;
 
-        press_button_1000(modules).into()
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let modules = parse_input(input);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        press_button(self.parameters.presses, input.clone()).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        ParsedParts::try_part_2(self, input).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        press_button_forever(modules).into()
+    fn try_part_2(&self, input: &Self::Input) -> Result<PartSolution, SolveError> {
+        press_button_forever(input.clone())
+            .map(Into::into)
+            .ok_or_else(|| SolveError::no_solution("`rx` never receives a single low pulse"))
     }
 }
 
 #[cfg(test)]
 mod test {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_20), ignore = "no input")]
         fn outcome() {
-            test_part_1!(763_500_168);
+            test_part_1!();
         }
 
         #[test]
@@ -402,15 +481,62 @@ mod test {
         fn example_2() {
             test_example_part_1!(11_687_500, 2);
         }
+
+        #[test]
+        fn example_1_once() {
+            test_example_part_1!(32, 1, presses: 1);
+        }
     }
 
     mod part_2 {
-        use advent_of_code_2023::test_part_2;
         use pretty_assertions::assert_eq;
 
+        use crate::test_part_2;
+
         #[test]
+        #[cfg_attr(not(has_input_2023_20), ignore = "no input")]
         fn outcome() {
-            test_part_2!(207_652_583_562_007_usize);
+            test_part_2!();
+        }
+    }
+
+    mod try_part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::Parts as _;
+        use crate::shared::error::SolveError;
+        use crate::shared::solution::read_file_part;
+
+        #[test]
+        fn unknown_module() {
+            assert_eq!(
+                Solution::default()
+                    .try_part_2("broadcaster -> a\n$a -> b")
+                    .unwrap_err(),
+                SolveError::parse_at(1, 0, "expected `broadcaster`, `%name` or `&name`")
+            );
+        }
+
+        #[test]
+        fn without_rx() {
+            assert_eq!(
+                Solution::default()
+                    .try_part_2(&read_file_part("examples", &super::super::PUZZLE, 1))
+                    .unwrap_err(),
+                SolveError::no_solution("`rx` never receives a single low pulse")
+            );
+        }
+
+        #[test]
+        fn cycles_before_rx() {
+            // `c` never receives a pulse, so `b` never sends a low pulse to `rx`
+            assert_eq!(
+                Solution::default()
+                    .try_part_2("broadcaster -> a\n%a -> b\n%c -> b\n&b -> rx")
+                    .unwrap_err(),
+                SolveError::no_solution("`rx` never receives a single low pulse")
+            );
         }
     }
 }
@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+use hashbrown::HashSet;
+
+use crate::shared::error::SolveError;
+use crate::shared::grids::infinite_grid::InfiniteGrid;
+use crate::shared::grids::{GridIter as _, Neighborhood, Neighbors as _};
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!(Parameters);
+
+pub struct Parameters {
+    /// How many steps the elf takes in part 1.
+    pub part_1_steps: NonZeroUsize,
+    /// How many steps the elf takes in part 2.
+    pub part_2_steps: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            part_1_steps: NonZeroUsize::new(64).expect("64 is not zero"),
+            part_2_steps: 26_501_365,
+        }
+    }
+}
+
+pub enum Tile {
+    Rock,
+    Garden(bool),
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Tile::Rock => write!(f, "#"),
+            Tile::Garden(false) => write!(f, "."),
+            Tile::Garden(true) => write!(f, "O"),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Tile::Rock),
+            '.' => Ok(Tile::Garden(false)),
+            'S' => Ok(Tile::Garden(true)),
+            _ => Err("expected `#`, `.` or `S`"),
+        }
+    }
+}
+
+/// Parses the map of the garden, and finds where the elf starts.
+///
+/// # Errors
+/// When the map contains something other than `#`, `.` and `S`, or has no `S`.
+pub fn parse_input(input: &str) -> Result<(InfiniteGrid<Tile>, (usize, usize)), SolveError> {
+    let map = InfiniteGrid::parse(input)?;
+
+    let start = map
+        .rows()
+        .enumerate()
+        .find_map(|(r, row)| {
+            row.iter()
+                .position(|t| matches!(*t, Tile::Garden(true)))
+                .map(|c| (r, c))
+        })
+        .ok_or_else(|| SolveError::no_solution("the map has no start"))?;
+
+    Ok((map, start))
+}
+
+/// Counts the garden plots that can be reached in exactly `steps` steps, repeating the map infinitely.
+///
+/// # Panics
+/// When the dimensions of the grid don't fit in an `isize`.
+#[must_use]
+pub fn bfs(grid: &InfiniteGrid<Tile>, start: (isize, isize), steps: NonZeroUsize) -> usize {
+    let neighborhood = Neighborhood::von_neumann(1);
+    let mut visited = HashSet::new();
+    let mut garden_plots = 0;
+
+    let even_odd = steps.get() % 2;
+
+    let mut dq = VecDeque::from_iter([(start, 0_usize)]);
+
+    while let Some((coord, steps_traveled)) = dq.pop_front() {
+        if steps_traveled > steps.get() || visited.contains(&coord) {
+            continue;
+        }
+
+        visited.insert(coord);
+
+        if steps_traveled % 2 == even_odd {
+            garden_plots += 1;
+        }
+
+        for (new_coord, _) in grid.neighborhood_iter(&neighborhood, coord.0, coord.1) {
+            if matches!(grid[new_coord], Tile::Garden(_)) {
+                dq.push_back((new_coord, steps_traveled + 1));
+            }
+        }
+    }
+
+    garden_plots
+}
+
+fn part_2(garden: &InfiniteGrid<Tile>, start: (usize, usize), steps: usize) -> usize {
+    // forgive me, for I am not smart enough to understand this problem
+    // this solution is the Rust version of https://github.com/terminalmage/adventofcode/blob/4a52a87f4af8908e4ef6df637680a04770a3a27e/2023/day21.py#L256
+    let steps: isize = steps.try_into().unwrap();
+
+    let columns = garden.get_column_length();
+
+    let edge = columns - 1 - start.1;
+
+    let [u0, u1, u2]: [usize; 3] = (0..3)
+        .map(|x| {
+            bfs(
+                garden,
+                (start.0.try_into().unwrap(), start.1.try_into().unwrap()),
+                NonZeroUsize::new(edge + (columns * x)).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+
+    let (u0, u1, u2): (isize, isize, isize) = (
+        u0.try_into().unwrap(),
+        u1.try_into().unwrap(),
+        u2.try_into().unwrap(),
+    );
+
+    let c = u0;
+
+    let a = isize::midpoint(u2 - (2 * u1), u0);
+
+    let b = u1 - u0 - a;
+
+    let n: isize = (steps - TryInto::<isize>::try_into(edge).unwrap())
+        / TryInto::<isize>::try_into(columns).unwrap();
+
+    ((a * n.pow(2)) + (b * n) + c).unsigned_abs()
+}
+
+impl ParsedParts for Solution {
+    type Input = (InfiniteGrid<Tile>, (usize, usize));
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let (ref map, start) = *input;
+
+        bfs(
+            map,
+            (start.0.try_into().unwrap(), start.1.try_into().unwrap()),
+            self.parameters.part_1_steps,
+        )
+        .into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let (ref map, start) = *input;
+
+        part_2(map, start, self.parameters.part_2_steps).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use std::num::NonZeroUsize;
+
+        use pretty_assertions::assert_eq;
+
+        use crate::{test_example_part_1, test_part_1};
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_21), ignore = "no input")]
+        fn outcome() {
+            test_part_1!();
+        }
+
+        #[test]
+        fn example() {
+            test_example_part_1!(2665);
+        }
+
+        #[test]
+        fn example_6_steps() {
+            test_example_part_1!(16, part_1_steps: NonZeroUsize::new(6).unwrap());
+        }
+
+        #[test]
+        fn example_beyond_the_map() {
+            test_example_part_1!(50, part_1_steps: NonZeroUsize::new(10).unwrap());
+            test_example_part_1!(1594, part_1_steps: NonZeroUsize::new(50).unwrap());
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use crate::test_part_2;
+
+        #[test]
+        #[cfg_attr(not(has_input_2023_21), ignore = "no input")]
+        fn outcome() {
+            test_part_2!();
+        }
+    }
+}
@@ -1,51 +1,66 @@
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::{HashMap, HashSet};
 
-advent_of_code_2023::solution!(490, 96356);
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Piece {
-    x: u32,
-    y: u32,
-    z: u32,
+pub struct Piece {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Brick {
-    start: Piece,
-    end: Piece,
+pub struct Brick {
+    pub start: Piece,
+    pub end: Piece,
 }
 
-fn parse_piece(piece: &str) -> Piece {
+fn parse_piece(piece: &str) -> Result<Piece, &'static str> {
     let piece: [u32; 3] = piece
         .split(',')
-        .map(|v| v.parse::<u32>().unwrap())
-        .collect::<Vec<u32>>()
+        .map(|v| v.parse::<u32>().map_err(|_| "Invalid coordinate"))
+        .collect::<Result<Vec<u32>, _>>()?
         .try_into()
-        .unwrap();
+        .map_err(|_| "expected 3 coordinates")?;
 
-    Piece {
+    Ok(Piece {
         x: piece[0],
         y: piece[1],
         z: piece[2],
-    }
+    })
 }
 
-fn parse_input(input: &str) -> Vec<Brick> {
+/// Parses the snapshot of the falling bricks.
+///
+/// # Errors
+/// When a brick is malformed.
+pub fn parse_input(input: &str) -> Result<Vec<Brick>, SolveError> {
     input
         .lines()
-        .map(|line| {
-            let (start, end) = line.split_once('~').unwrap();
-
-            Brick {
-                start: parse_piece(start),
-                end: parse_piece(end),
-            }
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (start, end) = line
+                .split_once('~')
+                .ok_or_else(|| SolveError::parse_at(line_index, 0, "expected `start~end`"))?;
+
+            // the end starts after the start and the `~`
+            let end_column = start.len() + 1;
+
+            Ok(Brick {
+                start: parse_piece(start)
+                    .map_err(|reason| SolveError::parse_at(line_index, 0, reason))?,
+                end: parse_piece(end)
+                    .map_err(|reason| SolveError::parse_at(line_index, end_column, reason))?,
+            })
         })
-        .collect::<Vec<Brick>>()
+        .collect::<Result<Vec<Brick>, _>>()
 }
 
-fn get_position_map(input: &[Brick]) -> HashMap<(u32, u32, u32), Brick> {
+#[must_use]
+pub fn get_position_map(input: &[Brick]) -> HashMap<(u32, u32, u32), Brick> {
     let mut position_map = HashMap::new();
 
     for brick in input {
@@ -61,7 +76,7 @@ fn get_position_map(input: &[Brick]) -> HashMap<(u32, u32, u32), Brick> {
     position_map
 }
 
-fn stabilize_bricks(
+pub fn stabilize_bricks(
     bricks: &mut [Brick],
     position_map: HashMap<(u32, u32, u32), Brick>,
 ) -> HashMap<(u32, u32, u32), Brick> {
@@ -121,7 +136,8 @@ fn stabilize_bricks(
     settled_positions
 }
 
-fn get_brick_dependencies(
+#[must_use]
+pub fn get_brick_dependencies(
     bricks: &[Brick],
     settled_map: &HashMap<(u32, u32, u32), Brick>,
 ) -> (
@@ -163,8 +179,8 @@ fn get_brick_dependencies(
     (above, below)
 }
 
-fn count_safe_to_remove(input: &str) -> PartSolution {
-    let mut bricks = parse_input(input);
+fn count_safe_to_remove(bricks: &[Brick]) -> PartSolution {
+    let mut bricks = bricks.to_vec();
 
     let position_map = get_position_map(&bricks);
 
@@ -194,8 +210,8 @@ fn count_safe_to_remove(input: &str) -> PartSolution {
     safe_to_remove.into()
 }
 
-fn count_total_bricks_disintegrated(input: &str) -> PartSolution {
-    let mut bricks = parse_input(input);
+fn count_total_bricks_disintegrated(bricks: &[Brick]) -> PartSolution {
+    let mut bricks = bricks.to_vec();
 
     let position_map = get_position_map(&bricks);
 
@@ -240,12 +256,18 @@ fn count_total_bricks_disintegrated(input: &str) -> PartSolution {
     total_bricks_disintegrated.into()
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
+impl ParsedParts for Solution {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
         count_safe_to_remove(input)
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
         count_total_bricks_disintegrated(input)
     }
 }
@@ -253,12 +275,14 @@ impl Parts for Solution {
 #[cfg(test)]
 mod tests {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_22), ignore = "no input")]
         fn outcome() {
-            test_part_1!(490);
+            test_part_1!();
         }
 
         #[test]
@@ -268,12 +292,14 @@ mod tests {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_22), ignore = "no input")]
         fn outcome() {
-            test_part_2!(96356);
+            test_part_2!();
         }
 
         #[test]
@@ -1,17 +1,17 @@
 #![expect(clippy::type_complexity, reason = "It's Advent of Code")]
-use advent_of_code_2023::shared::grids::grid::Grid;
-use advent_of_code_2023::shared::grids::{
-    GridIter as _, HorizontalVerticalDirection, Neighbors as _,
-};
-use advent_of_code_2023::shared::{PartSolution, Parts};
 use hashbrown::hash_map::Entry;
 use hashbrown::{HashMap, HashSet};
 use pretty_assertions::assert_matches;
 
-advent_of_code_2023::solution!(490, 6726);
+use crate::shared::error::SolveError;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::{GridIter as _, HorizontalVerticalDirection, Neighbors as _};
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
 
 #[derive(Debug)]
-enum Block {
+pub enum Block {
     Open,
     Closed,
     Slope(HorizontalVerticalDirection),
@@ -48,20 +48,8 @@ impl TryFrom<char> for Block {
     }
 }
 
-fn parse_input(input: &str) -> Grid<Block> {
-    let grid = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| Block::try_from(c).expect("Invalid input"))
-                .collect()
-        })
-        .collect();
-
-    Grid::new(grid)
-}
-
-fn find_longest_path(grid: &Grid<Block>) -> Option<usize> {
+#[must_use]
+pub fn find_longest_path(grid: &Grid<Block>) -> Option<usize> {
     let start = (0, 1);
 
     let end = (grid.get_row_length() - 1, grid.get_column_length() - 2);
@@ -103,7 +91,7 @@ fn go_forth(
                 // good, continue and evaluate neighbors
                 // possible optimization: don't consider the position we're coming from
                 // as a neighbor, avoiding the need of looking it up
-                // in or hv_neighbors filter call
+                // in our hv_neighbors_iter filter call
                 (row_index, column_index)
             },
             Block::Closed => {
@@ -135,39 +123,43 @@ fn go_forth(
             continue;
         }
 
-        let neighbors = grid.hv_neighbors(row_index, column_index);
+        let mut neighbors = grid.hv_neighbors_iter(row_index, column_index).filter(
+            |&((neighbor_row_index, neighbor_column_index), neighbor_direction)| {
+                !matches!(
+                    grid[neighbor_row_index][neighbor_column_index],
+                    Block::Closed
+                ) && (!direction) != neighbor_direction
+            },
+        );
 
-        let neighbors = neighbors
-            .iter()
-            .filter(|&&(ref coordinates, neighbor_direction)| {
-                !matches!(grid[coordinates.0][coordinates.1], Block::Closed)
-                    && (!direction) != neighbor_direction
-            })
-            .collect::<Vec<_>>();
+        // a dead end
+        let Some(first) = neighbors.next() else {
+            continue;
+        };
 
-        match &*neighbors {
-            &[] => {},
-            &[&(neighbor_coordinates, direction)] => {
-                list.push((neighbor_coordinates, direction, history, actual_count + 1));
-            },
-            many @ &[..] => {
-                // store the intersection in case we come here again
-                if history
-                    .insert((row_index, column_index), direction)
-                    .is_some()
-                {
-                    panic!("NO");
-                }
-
-                for &&(neighbor_coordinates, direction) in many {
-                    list.push((
-                        neighbor_coordinates,
-                        direction,
-                        history.clone(),
-                        actual_count + 1,
-                    ));
-                }
-            },
+        let Some(second) = neighbors.next() else {
+            let (neighbor_coordinates, direction) = first;
+
+            list.push((neighbor_coordinates, direction, history, actual_count + 1));
+
+            continue;
+        };
+
+        // store the intersection in case we come here again
+        if history
+            .insert((row_index, column_index), direction)
+            .is_some()
+        {
+            panic!("NO");
+        }
+
+        for (neighbor_coordinates, direction) in [first, second].into_iter().chain(neighbors) {
+            list.push((
+                neighbor_coordinates,
+                direction,
+                history.clone(),
+                actual_count + 1,
+            ));
         }
     }
 
@@ -175,7 +167,7 @@ fn go_forth(
 }
 
 #[derive(Clone)]
-struct Graph {
+pub struct Graph {
     map: HashMap<(usize, usize), HashMap<(usize, usize), usize>>,
 }
 
@@ -207,7 +199,8 @@ impl Graph {
     }
 }
 
-fn brute_force_graph(grid: &Grid<Block>) -> usize {
+#[must_use]
+pub fn brute_force_graph(grid: &Grid<Block>) -> usize {
     let start = (0, 1);
     let end = (grid.get_row_length() - 1, grid.get_column_length() - 2);
 
@@ -224,35 +217,39 @@ fn brute_force_graph(grid: &Grid<Block>) -> usize {
             continue;
         }
 
-        let neighbors = grid.hv_neighbors(row_index, column_index);
+        let mut neighbors = grid.hv_neighbors_iter(row_index, column_index).filter(
+            |&((neighbor_row_index, neighbor_column_index), neighbor_direction)| {
+                !matches!(
+                    grid[neighbor_row_index][neighbor_column_index],
+                    Block::Closed
+                ) && (!direction) != neighbor_direction
+            },
+        );
+
+        // a dead end
+        let Some(first) = neighbors.next() else {
+            continue;
+        };
 
-        let neighbors = neighbors
-            .iter()
-            .filter(|&&(ref coordinates, neighbor_direction)| {
-                !matches!(grid[coordinates.0][coordinates.1], Block::Closed)
-                    && (!direction) != neighbor_direction
-            })
-            .collect::<Vec<_>>();
+        let Some(second) = neighbors.next() else {
+            let (neighbor, neighbor_direction) = first;
 
-        match &*neighbors {
-            &[] => continue,
-            &[&(neighbor, neighbor_direction)] => {
-                list.push(((from, neighbor), neighbor_direction, distance + 1));
-            },
-            all @ &[..] => {
-                // more than 1 neighbor, we are at an intersection, record distance
+            list.push(((from, neighbor), neighbor_direction, distance + 1));
 
-                graph.record(from, (row_index, column_index), distance);
+            continue;
+        };
 
-                // mark intersection as visited
-                if !visited.insert((row_index, column_index)) {
-                    continue;
-                }
+        // more than 1 neighbor, we are at an intersection, record distance
 
-                for &&(neighbor, neighbor_direction) in all {
-                    list.push((((row_index, column_index), neighbor), neighbor_direction, 1));
-                }
-            },
+        graph.record(from, (row_index, column_index), distance);
+
+        // mark intersection as visited
+        if !visited.insert((row_index, column_index)) {
+            continue;
+        }
+
+        for (neighbor, neighbor_direction) in [first, second].into_iter().chain(neighbors) {
+            list.push((((row_index, column_index), neighbor), neighbor_direction, 1));
         }
     }
 
@@ -316,20 +313,22 @@ fn brute_force_graph(grid: &Grid<Block>) -> usize {
     longest
 }
 
-impl Parts for Solution {
-    fn part_1(&self, input: &str) -> PartSolution {
-        let parsed = parse_input(input);
+impl ParsedParts for Solution {
+    type Input = Grid<Block>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input)?)
+    }
 
-        let longest_path = find_longest_path(&parsed);
+    fn part_1(&self, input: &Self::Input) -> PartSolution {
+        let longest_path = find_longest_path(input);
 
         // -1 because we don't want to count start
         PartSolution::USize(longest_path.unwrap_or_default())
     }
 
-    fn part_2(&self, input: &str) -> PartSolution {
-        let parsed = parse_input(input);
-
-        let longest_path = brute_force_graph(&parsed);
+    fn part_2(&self, input: &Self::Input) -> PartSolution {
+        let longest_path = brute_force_graph(input);
 
         // -1 because we don't want to count start
         PartSolution::USize(longest_path)
@@ -339,12 +338,14 @@ impl Parts for Solution {
 #[cfg(test)]
 mod tests {
     mod part_1 {
-        use advent_of_code_2023::{test_example_part_1, test_part_1};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_1, test_part_1};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_23), ignore = "no input")]
         fn outcome() {
-            test_part_1!(2502);
+            test_part_1!();
         }
 
         #[test]
@@ -354,12 +355,14 @@ mod tests {
     }
 
     mod part_2 {
-        use advent_of_code_2023::{test_example_part_2, test_part_2};
         use pretty_assertions::assert_eq;
 
+        use crate::{test_example_part_2, test_part_2};
+
         #[test]
+        #[cfg_attr(not(has_input_2023_23), ignore = "no input")]
         fn outcome() {
-            test_part_2!(6726);
+            test_part_2!();
         }
 
         #[test]
@@ -1,14 +1,153 @@
 use std::cmp::Ordering;
 
+use num_bigint::BigInt;
+
+use self::error::SolveError;
+
+pub mod answers;
+pub mod baseline;
 pub mod day;
+pub mod error;
+pub mod fetch;
 pub mod grids;
+pub mod input;
+pub mod ocr;
+pub mod puzzle;
+pub mod results;
+pub mod scaffold;
 pub mod solution;
+pub mod submit;
+pub mod timing;
 pub mod tree;
 pub mod utils;
+pub mod year;
 
 pub trait Parts {
     fn part_1(&self, input: &str) -> PartSolution;
     fn part_2(&self, input: &str) -> PartSolution;
+
+    /// Like [`Parts::part_1`], but reports bad input, or the lack of an answer, as a [`SolveError`].
+    ///
+    /// # Errors
+    /// When the input is malformed, or has no solution.
+    fn try_part_1(&self, input: &str) -> Result<PartSolution, SolveError> {
+        Ok(self.part_1(input))
+    }
+
+    /// Like [`Parts::part_2`], but reports bad input, or the lack of an answer, as a [`SolveError`].
+    ///
+    /// # Errors
+    /// When the input is malformed, or has no solution.
+    fn try_part_2(&self, input: &str) -> Result<PartSolution, SolveError> {
+        Ok(self.part_2(input))
+    }
+
+    /// Does the work both parts share, so the runner can time it on its own.
+    ///
+    /// By default nothing is shared, and each part gets the raw input.
+    ///
+    /// # Errors
+    /// When the input is malformed.
+    fn prepare<'a>(&'a self, input: &'a str) -> Result<Box<dyn Prepared + 'a>, SolveError> {
+        Ok(Box::new(Unparsed { parts: self, input }))
+    }
+}
+
+/// An input that is ready to be solved, as returned by [`Parts::prepare`].
+pub trait Prepared {
+    /// # Errors
+    /// When the input has no solution.
+    fn try_part_1(&self) -> Result<PartSolution, SolveError>;
+
+    /// # Errors
+    /// When the input has no solution.
+    fn try_part_2(&self) -> Result<PartSolution, SolveError>;
+}
+
+struct Unparsed<'a, P: ?Sized> {
+    parts: &'a P,
+    input: &'a str,
+}
+
+impl<P: Parts + ?Sized> Prepared for Unparsed<'_, P> {
+    fn try_part_1(&self) -> Result<PartSolution, SolveError> {
+        self.parts.try_part_1(self.input)
+    }
+
+    fn try_part_2(&self) -> Result<PartSolution, SolveError> {
+        self.parts.try_part_2(self.input)
+    }
+}
+
+/// Like [`Parts`], but the input is parsed once, and both parts solve the parsed input.
+///
+/// Every [`ParsedParts`] is also [`Parts`], which parses the input for each part it solves.
+pub trait ParsedParts {
+    /// The parsed input. It is owned, so it can outlive the raw input it was parsed from.
+    type Input: 'static;
+
+    /// # Errors
+    /// When the input is malformed.
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+
+    fn part_1(&self, input: &Self::Input) -> PartSolution;
+    fn part_2(&self, input: &Self::Input) -> PartSolution;
+
+    /// Like [`ParsedParts::part_1`], but reports the lack of an answer as a [`SolveError`].
+    ///
+    /// # Errors
+    /// When the input has no solution.
+    fn try_part_1(&self, input: &Self::Input) -> Result<PartSolution, SolveError> {
+        Ok(self.part_1(input))
+    }
+
+    /// Like [`ParsedParts::part_2`], but reports the lack of an answer as a [`SolveError`].
+    ///
+    /// # Errors
+    /// When the input has no solution.
+    fn try_part_2(&self, input: &Self::Input) -> Result<PartSolution, SolveError> {
+        Ok(self.part_2(input))
+    }
+}
+
+struct Parsed<'a, P: ParsedParts> {
+    parts: &'a P,
+    input: P::Input,
+}
+
+impl<P: ParsedParts> Prepared for Parsed<'_, P> {
+    fn try_part_1(&self) -> Result<PartSolution, SolveError> {
+        ParsedParts::try_part_1(self.parts, &self.input)
+    }
+
+    fn try_part_2(&self) -> Result<PartSolution, SolveError> {
+        ParsedParts::try_part_2(self.parts, &self.input)
+    }
+}
+
+impl<P: ParsedParts> Parts for P {
+    fn part_1(&self, input: &str) -> PartSolution {
+        Parts::try_part_1(self, input).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        Parts::try_part_2(self, input).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_part_1(&self, input: &str) -> Result<PartSolution, SolveError> {
+        ParsedParts::try_part_1(self, &P::parse(input)?)
+    }
+
+    fn try_part_2(&self, input: &str) -> Result<PartSolution, SolveError> {
+        ParsedParts::try_part_2(self, &P::parse(input)?)
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> Result<Box<dyn Prepared + 'a>, SolveError> {
+        Ok(Box::new(Parsed {
+            parts: self,
+            input: P::parse(input)?,
+        }))
+    }
 }
 
 pub enum PartSolution {
@@ -16,11 +155,16 @@ pub enum PartSolution {
     U32(u32),
     I64(i64),
     U64(u64),
+    I128(i128),
+    U128(u128),
     ISize(isize),
     USize(usize),
+    BigInt(BigInt),
+    Bool(bool),
     String(String),
     Vec(Vec<String>),
-    Manual,
+    /// An answer a human has to read, e.g. from letters drawn in pixels that we didn't recognize.
+    Manual(Vec<String>),
     None,
 }
 
@@ -31,11 +175,15 @@ impl std::fmt::Debug for PartSolution {
             Self::U32(arg0) => write!(f, "{}u32", arg0),
             Self::I64(arg0) => write!(f, "{}i64", arg0),
             Self::U64(arg0) => write!(f, "{}u64", arg0),
+            Self::I128(arg0) => write!(f, "{}i128", arg0),
+            Self::U128(arg0) => write!(f, "{}u128", arg0),
             Self::ISize(arg0) => write!(f, "{}isize", arg0),
             Self::USize(arg0) => write!(f, "{}usize", arg0),
+            Self::BigInt(ref arg0) => write!(f, "BigInt({})", arg0),
+            Self::Bool(arg0) => write!(f, "{}", arg0),
             Self::String(ref arg0) => write!(f, "\"{}\"", arg0),
             Self::Vec(ref arg0) => write!(f, "{:?}", arg0),
-            Self::Manual => write!(f, "Manual"),
+            Self::Manual(ref art) => write!(f, "Manual({:?})", art),
             Self::None => write!(f, "None"),
         }
     }
@@ -46,25 +194,65 @@ impl PartSolution {
     pub fn has_solution(&self) -> bool {
         !matches!(*self, PartSolution::None)
     }
+
+    /// The value of an integer answer, whichever integer type the solution returned it as.
+    ///
+    /// Answers compare by this value, so e.g. `I32(5)` equals `U128(5)`, and `ISize(-1)` is less
+    /// than `USize(0)`.
+    #[must_use]
+    pub fn to_integer(&self) -> Option<BigInt> {
+        match *self {
+            PartSolution::I32(i) => Some(i.into()),
+            PartSolution::U32(i) => Some(i.into()),
+            PartSolution::I64(i) => Some(i.into()),
+            PartSolution::U64(i) => Some(i.into()),
+            PartSolution::I128(i) => Some(i.into()),
+            PartSolution::U128(i) => Some(i.into()),
+            PartSolution::ISize(i) => Some(i.into()),
+            PartSolution::USize(i) => Some(i.into()),
+            PartSolution::BigInt(ref i) => Some(i.clone()),
+            PartSolution::Bool(_)
+            | PartSolution::String(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Manual(_)
+            | PartSolution::None => None,
+        }
+    }
 }
 
 impl PartialEq<PartSolution> for PartSolution {
     fn eq(&self, other: &PartSolution) -> bool {
-        match *self {
-            PartSolution::I32(ref i) => i == other,
-            PartSolution::U32(ref i) => i == other,
-            PartSolution::I64(ref i) => i == other,
-            PartSolution::U64(ref i) => i == other,
-            PartSolution::ISize(ref i) => i == other,
-            PartSolution::USize(ref i) => i == other,
-            PartSolution::String(ref i) => i == other,
-            PartSolution::Vec(ref i) => i == other,
-            PartSolution::None => matches!(other, &PartSolution::None),
-            PartSolution::Manual => matches!(other, &PartSolution::Manual),
+        if let (Some(left), Some(right)) = (self.to_integer(), other.to_integer()) {
+            return left == right;
+        }
+
+        match (self, other) {
+            (&PartSolution::Bool(left), &PartSolution::Bool(right)) => left == right,
+            (&PartSolution::String(ref left), &PartSolution::String(ref right)) => left == right,
+            (&PartSolution::Vec(ref left), &PartSolution::Vec(ref right))
+            | (&PartSolution::Manual(ref left), &PartSolution::Manual(ref right)) => left == right,
+            (&PartSolution::None, &PartSolution::None) => true,
+            _ => false,
         }
     }
 }
 
+/// Integers order by value, and strings lexicographically. Anything else is only ever equal, or
+/// not comparable at all.
+impl PartialOrd<PartSolution> for PartSolution {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+        if let (Some(left), Some(right)) = (self.to_integer(), other.to_integer()) {
+            return Some(left.cmp(&right));
+        }
+
+        if let (&PartSolution::String(ref left), &PartSolution::String(ref right)) = (self, other) {
+            return Some(left.cmp(right));
+        }
+
+        (self == other).then_some(Ordering::Equal)
+    }
+}
+
 impl From<i32> for PartSolution {
     fn from(v: i32) -> Self {
         PartSolution::I32(v)
@@ -89,6 +277,18 @@ impl From<u64> for PartSolution {
     }
 }
 
+impl From<i128> for PartSolution {
+    fn from(v: i128) -> Self {
+        PartSolution::I128(v)
+    }
+}
+
+impl From<u128> for PartSolution {
+    fn from(v: u128) -> Self {
+        PartSolution::U128(v)
+    }
+}
+
 impl From<isize> for PartSolution {
     fn from(v: isize) -> Self {
         PartSolution::ISize(v)
@@ -101,6 +301,18 @@ impl From<usize> for PartSolution {
     }
 }
 
+impl From<BigInt> for PartSolution {
+    fn from(v: BigInt) -> Self {
+        PartSolution::BigInt(v)
+    }
+}
+
+impl From<bool> for PartSolution {
+    fn from(v: bool) -> Self {
+        PartSolution::Bool(v)
+    }
+}
+
 impl From<Vec<String>> for PartSolution {
     fn from(v: Vec<String>) -> Self {
         PartSolution::Vec(v)
@@ -135,11 +347,19 @@ impl std::fmt::Display for PartSolution {
             PartSolution::U32(other) => other.to_string(),
             PartSolution::I64(other) => other.to_string(),
             PartSolution::U64(other) => other.to_string(),
+            PartSolution::I128(other) => other.to_string(),
+            PartSolution::U128(other) => other.to_string(),
             PartSolution::ISize(other) => other.to_string(),
             PartSolution::USize(other) => other.to_string(),
+            PartSolution::BigInt(ref other) => other.to_string(),
+            PartSolution::Bool(other) => other.to_string(),
             PartSolution::String(ref other) => other.to_owned(),
             PartSolution::Vec(ref other) => format!("\n{}", other.join("\n")),
-            PartSolution::Manual => "Manual".to_owned(),
+            PartSolution::Manual(ref art) => {
+                art.iter().fold(String::from("Manual"), |manual, line| {
+                    format!("{}\n{}", manual, line)
+                })
+            },
             PartSolution::None => "None".to_owned(),
         };
 
@@ -147,336 +367,182 @@ impl std::fmt::Display for PartSolution {
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for i32 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => *self == other,
-            PartSolution::U32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
+/// Compares plain values with answers, the way the answers compare among themselves, so tests can
+/// `assert_eq!(374, solution.part_1(input))`.
+macro_rules! compare_with_part_solution {
+    (copy: $($t:ty),*; owned: $($owned:ty),*) => {
+        $(
+            impl PartialEq<PartSolution> for $t {
+                fn eq(&self, other: &PartSolution) -> bool {
+                    let this = PartSolution::from(*self);
 
-impl std::cmp::PartialEq<PartSolution> for u32 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::U32(other) => *self == other,
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => u64::from(*self) == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
+                    this == *other
+                }
+            }
 
-impl std::cmp::PartialEq<PartSolution> for i64 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => *self == Self::from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => *self == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
+            impl PartialOrd<PartSolution> for $t {
+                fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+                    PartSolution::from(*self).partial_cmp(other)
+                }
+            }
+        )*
+        $(
+            impl PartialEq<PartSolution> for $owned {
+                fn eq(&self, other: &PartSolution) -> bool {
+                    let this = PartSolution::from(self.clone());
 
-impl std::cmp::PartialEq<PartSolution> for u64 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => *self == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
+                    this == *other
+                }
+            }
+
+            impl PartialOrd<PartSolution> for $owned {
+                fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+                    PartSolution::from(self.clone()).partial_cmp(other)
+                }
+            }
+        )*
+    };
 }
 
-impl std::cmp::PartialEq<PartSolution> for isize {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => *self == other,
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
+compare_with_part_solution!(
+    copy: i32, u32, i64, u64, i128, u128, isize, usize, bool;
+    owned: BigInt, String, Vec<String>
+);
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use num_bigint::BigInt;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::shared::error::SolveError;
+    use crate::shared::{ParsedParts, PartSolution, Parts};
+
+    /// Sums the numbers on each line for part 1, and multiplies them for part 2.
+    struct Numbers;
+
+    impl ParsedParts for Numbers {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(line_index, line)| {
+                    line.parse()
+                        .map_err(|_| SolveError::parse_at(line_index, 0, "expected a number"))
+                })
+                .collect()
         }
-    }
-}
 
-impl std::cmp::PartialEq<PartSolution> for usize {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => *self == other,
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
+        fn part_1(&self, input: &Self::Input) -> PartSolution {
+            input.iter().sum::<u32>().into()
         }
-    }
-}
 
-impl std::cmp::PartialEq<PartSolution> for String {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::String(ref s) => s == self,
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
+        fn part_2(&self, input: &Self::Input) -> PartSolution {
+            input.iter().product::<u32>().into()
         }
     }
-}
 
-impl std::cmp::PartialEq<PartSolution> for Vec<String> {
-    fn eq(&self, other: &PartSolution) -> bool {
-        matches!(*other, PartSolution::Vec(ref v) if {
-            if v.len() != self.len() {
-                return false;
-            }
+    #[test]
+    fn parsed_parts_are_parts() {
+        assert_eq!(Parts::part_1(&Numbers, "2\n3\n4"), PartSolution::U32(9));
+        assert_eq!(Parts::part_2(&Numbers, "2\n3\n4"), PartSolution::U32(24));
+        assert_eq!(
+            Parts::try_part_1(&Numbers, "2\nx"),
+            Err(SolveError::parse_at(1, 0, "expected a number"))
+        );
+    }
 
-            for (l, r) in self.iter().zip(v) {
-                if l != r {
-                    return false;
-                }
-            }
+    #[test]
+    fn prepare_parses_once_for_both_parts() {
+        let prepared = Numbers.prepare("2\n3\n4").unwrap();
 
-            true
-        })
+        assert_eq!(prepared.try_part_1(), Ok(PartSolution::U32(9)));
+        assert_eq!(prepared.try_part_2(), Ok(PartSolution::U32(24)));
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for i32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(ref other) => self.partial_cmp(other),
-            PartSolution::U32(other) => i64::from(*self).partial_cmp(&i64::from(other)),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+    #[test]
+    fn prepare_reports_malformed_input() {
+        assert_eq!(
+            Numbers.prepare("x").err(),
+            Some(SolveError::parse_at(0, 0, "expected a number"))
+        );
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for u32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(ref other) => self.partial_cmp(other),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(ref other) => u64::from(*self).partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller u32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+    #[test]
+    fn cross_numeric_order() {
+        assert!(PartSolution::I32(-1) < PartSolution::USize(0));
+        assert!(PartSolution::U64(7) > PartSolution::ISize(-7));
+        assert!(PartSolution::I64(5) < PartSolution::U32(6));
+        assert!(PartSolution::ISize(6) > PartSolution::I64(5));
+        assert!(PartSolution::USize(10) > PartSolution::USize(9));
+        assert_eq!(
+            PartSolution::U32(3).partial_cmp(&PartSolution::I64(3)),
+            Some(Ordering::Equal)
+        );
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for u64 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(other) => self.partial_cmp(&u64::from(other)),
-            PartSolution::I64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U64(ref other) => self.partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+    #[test]
+    fn strings_order_lexicographically() {
+        assert!(PartSolution::from("abc") < PartSolution::from("abd"));
+        assert_eq!(
+            PartSolution::from("abc").partial_cmp(&PartSolution::U32(1)),
+            None
+        );
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for usize {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U32(other) => {
-                // if this fails, that means that usize is smaller than u32
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::I64(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U64(other) => {
-                // if this fails, that means that usize is smaller than u64
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => other.partial_cmp(self),
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+    #[test]
+    fn unknown_answers_are_only_equal() {
+        assert_eq!(
+            PartSolution::None.partial_cmp(&PartSolution::None),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            PartSolution::Manual(vec![]).partial_cmp(&PartSolution::None),
+            None
+        );
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for String {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::String(ref s) => s.partial_cmp(self),
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
+    #[test]
+    fn manual_answers_compare_by_contents() {
+        let art = || PartSolution::Manual(vec![String::from("#..#"), String::from("####")]);
 
-impl std::cmp::PartialOrd<PartSolution> for Vec<String> {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::Vec(ref v) => {
-                if v.len() != self.len() {
-                    return None;
-                }
+        assert_eq!(art(), art());
+        assert_ne!(art(), PartSolution::Manual(vec![String::from("####")]));
+    }
 
-                for (l, r) in self.iter().zip(v) {
-                    if l != r {
-                        return None;
-                    }
-                }
+    #[test]
+    fn wide_integers_compare_by_value() {
+        let beyond_u128 = BigInt::from(u128::MAX) + 1_u8;
+
+        assert_eq!(PartSolution::I32(5), PartSolution::U128(5));
+        assert_eq!(
+            PartSolution::BigInt(BigInt::from(-7)),
+            PartSolution::I64(-7)
+        );
+        assert_eq!(
+            207_652_583_562_007_u64,
+            PartSolution::I128(207_652_583_562_007)
+        );
+
+        assert!(PartSolution::I128(-1) < PartSolution::U64(0));
+        assert!(PartSolution::U128(u128::MAX) > PartSolution::I128(i128::MAX));
+        assert!(PartSolution::BigInt(beyond_u128) > PartSolution::U128(u128::MAX));
+        assert!(
+            PartSolution::BigInt(BigInt::from(i128::MIN) - 1_u8) < PartSolution::I128(i128::MIN)
+        );
+    }
 
-                Some(Ordering::Equal)
-            },
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::String(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+    #[test]
+    fn booleans_are_only_equal() {
+        assert_eq!(true, PartSolution::Bool(true));
+        assert_ne!(PartSolution::Bool(false), PartSolution::I32(0));
+        assert_eq!(
+            PartSolution::Bool(false).partial_cmp(&PartSolution::Bool(true)),
+            None
+        );
+        assert_eq!(PartSolution::Bool(true).to_string(), "true");
     }
 }
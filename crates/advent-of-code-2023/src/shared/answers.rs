@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use toml::de::Error as TomlError;
+use toml::{Table, Value};
+
+use super::PartSolution;
+use super::day::Day;
+use super::puzzle::PuzzleId;
+use super::solution::{ReadFileError, try_read_data_file};
+use super::year::Year;
+
+/// The name of the answers manifest, relative to the folder of its year in the data folder.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The answers we expect for the inputs of a year, keyed by day.
+///
+/// Each day is a table with a `part_1` and a `part_2` key. A missing key, or `"None"`, means we
+/// don't know the answer yet, `"Manual"` means the answer needs a human to read it, so it is never
+/// compared. Integers compare by value with whatever integer type a solution returns. TOML
+/// integers stop at `i64::MAX`, so larger answers are written as a string of digits, which we read
+/// as an integer too. Any other string, e.g. `"007"` or `"12"`, stays a string.
+///
+/// ```toml
+/// [17]
+/// part_1 = 928
+/// part_2 = "18446744073709551616"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<Day, [PartSolution; 2]>,
+}
+
+impl Answers {
+    /// Loads `YYYY/answers.toml` from the data folder.
+    ///
+    /// # Errors
+    /// When the manifest cannot be read, or is malformed.
+    pub fn load(year: Year) -> Result<Self, AnswersError> {
+        try_read_data_file(Path::new(&year.to_string()).join(ANSWERS_FILE))?.parse()
+    }
+
+    /// Loads `YYYY/inputs/<profile>/answers.toml` from the data folder, for the inputs of a profile.
+    ///
+    /// A profile without a manifest has no known answers.
+    ///
+    /// # Errors
+    /// When the manifest exists, but cannot be read, or is malformed.
+    pub fn load_profile(year: Year, profile: &str) -> Result<Self, AnswersError> {
+        let path = Path::new(&year.to_string())
+            .join("inputs")
+            .join(profile)
+            .join(ANSWERS_FILE);
+
+        match try_read_data_file(path) {
+            Ok(contents) => contents.parse(),
+            Err(error) if error.is_not_found() => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// The days of `days` the manifest doesn't list.
+    ///
+    /// Days listed without answers, as a new scaffold leaves them, count as listed: we just don't
+    /// know their answers yet.
+    pub fn unlisted<I: IntoIterator<Item = Day>>(&self, days: I) -> Vec<Day> {
+        days.into_iter()
+            .filter(|day| !self.days.contains_key(day))
+            .collect()
+    }
+
+    /// Takes the answers of `day` out of the manifest. Unknown days have no answers.
+    pub fn take(&mut self, day: Day) -> [PartSolution; 2] {
+        self.days
+            .remove(&day)
+            .unwrap_or([PartSolution::None, PartSolution::None])
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = s.parse::<Table>().map_err(AnswersError::Toml)?;
+
+        let mut days = BTreeMap::new();
+
+        for (key, value) in table {
+            let invalid = |reason| AnswersError::Invalid {
+                key: key.clone(),
+                reason,
+            };
+
+            let day = key.parse::<Day>().map_err(|_| invalid("not a day"))?;
+
+            let Value::Table(mut parts) = value else {
+                return Err(invalid("expected a table with `part_1` and `part_2`"));
+            };
+
+            let mut part = |name| {
+                parts
+                    .remove(name)
+                    .map_or(Ok(PartSolution::None), to_part_solution)
+                    .map_err(invalid)
+            };
+
+            let answers = [part("part_1")?, part("part_2")?];
+
+            if !parts.is_empty() {
+                return Err(invalid("only `part_1` and `part_2` are allowed"));
+            }
+
+            days.insert(day, answers);
+        }
+
+        Ok(Self { days })
+    }
+}
+
+fn to_part_solution(value: Value) -> Result<PartSolution, &'static str> {
+    match value {
+        Value::Integer(i) => Ok(PartSolution::I64(i)),
+        Value::Boolean(b) => Ok(PartSolution::Bool(b)),
+        Value::String(s) if s == "None" => Ok(PartSolution::None),
+        Value::String(s) if s == "Manual" => Ok(PartSolution::Manual(vec![])),
+        Value::String(s) => Ok(match s.parse::<BigInt>() {
+            // only what a TOML integer can't hold, written the way we'd print it, so e.g. `"007"`
+            // stays a string
+            Ok(integer) if i64::try_from(&integer).is_err() && integer.to_string() == s => {
+                PartSolution::BigInt(integer)
+            },
+            Ok(_) | Err(_) => PartSolution::String(s),
+        }),
+        Value::Array(lines) => lines
+            .into_iter()
+            .map(|line| match line {
+                Value::String(s) => Ok(s),
+                Value::Integer(_)
+                | Value::Float(_)
+                | Value::Boolean(_)
+                | Value::Datetime(_)
+                | Value::Array(_)
+                | Value::Table(_) => Err("expected an array of strings"),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(PartSolution::Vec),
+        Value::Float(_) | Value::Datetime(_) | Value::Table(_) => {
+            Err("expected an integer, a boolean, a string or an array of strings")
+        },
+    }
+}
+
+/// The answers of a single puzzle, as listed in the [`ANSWERS_FILE`] of its year.
+///
+/// # Panics
+/// When the manifest cannot be read, or is malformed.
+#[must_use]
+pub fn expected(puzzle: PuzzleId) -> [PartSolution; 2] {
+    Answers::load(puzzle.year())
+        .unwrap_or_else(|error| panic!("{}", error))
+        .take(puzzle.day())
+}
+
+/// An error which can be returned when loading the [`Answers`].
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(ReadFileError),
+    Toml(TomlError),
+    Invalid { key: String, reason: &'static str },
+}
+
+impl From<ReadFileError> for AnswersError {
+    fn from(error: ReadFileError) -> Self {
+        Self::Read(error)
+    }
+}
+
+impl Error for AnswersError {}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Read(ref error) => write!(f, "{}: {}", ANSWERS_FILE, error),
+            Self::Toml(ref error) => write!(f, "{}: {}", ANSWERS_FILE, error),
+            Self::Invalid { ref key, reason } => {
+                write!(f, "{}: invalid entry `{}`: {}", ANSWERS_FILE, key, reason)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::day;
+    use crate::days::{YEAR, puzzles};
+    use crate::shared::PartSolution;
+    use crate::shared::answers::{Answers, AnswersError};
+    use crate::shared::day::Day;
+    use crate::shared::puzzle::PuzzleId;
+
+    #[test]
+    fn parse() {
+        let mut answers = r##"
+            [01]
+            part_1 = 54159
+            part_2 = "Manual"
+
+            [10]
+            part_1 = ["#..#", ".##."]
+
+            [24]
+            part_1 = "None"
+            part_2 = "abc"
+
+            [25]
+            part_1 = true
+        "##
+        .parse::<Answers>()
+        .unwrap();
+
+        assert_eq!(
+            answers.take(day!(1)),
+            [54159_u32.into(), PartSolution::Manual(vec![])]
+        );
+        assert_eq!(
+            answers.take(day!(10)),
+            [
+                vec![String::from("#..#"), String::from(".##.")].into(),
+                PartSolution::None
+            ]
+        );
+        assert_eq!(answers.take(day!(24)), [PartSolution::None, "abc".into()]);
+        assert_eq!(
+            answers.take(day!(25)),
+            [PartSolution::Bool(true), PartSolution::None]
+        );
+        assert_eq!(
+            answers.take(day!(25)),
+            [PartSolution::None, PartSolution::None]
+        );
+    }
+
+    #[test]
+    fn wider_than_toml_integers() {
+        let mut answers =
+            "[01]\npart_1 = \"18446744073709551616\"\npart_2 = \"-9223372036854775809\""
+                .parse::<Answers>()
+                .unwrap();
+
+        let [part_1, part_2] = answers.take(day!(1));
+
+        assert_eq!(part_1, PartSolution::U128(u128::from(u64::MAX) + 1));
+        assert_eq!(part_2, PartSolution::I128(i128::from(i64::MIN) - 1));
+    }
+
+    #[test]
+    fn other_digit_strings() {
+        let mut answers = "[01]\npart_1 = \"007\"\npart_2 = \"12\""
+            .parse::<Answers>()
+            .unwrap();
+
+        let [part_1, part_2] = answers.take(day!(1));
+
+        assert_eq!(part_1, PartSolution::from("007"));
+        assert_eq!(part_2, PartSolution::from("12"));
+    }
+
+    #[test]
+    fn invalid_day() {
+        let error = "[26]\npart_1 = 1".parse::<Answers>().unwrap_err();
+
+        assert!(matches!(error, AnswersError::Invalid { ref key, .. } if key == "26"));
+    }
+
+    #[test]
+    fn invalid_value() {
+        let error = "[01]\npart_1 = 1.5".parse::<Answers>().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "answers.toml: invalid entry `01`: expected an integer, a boolean, a string or an array of \
+             strings"
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        let error = "[01]\npart_3 = 1".parse::<Answers>().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "answers.toml: invalid entry `01`: only `part_1` and `part_2` are allowed"
+        );
+    }
+
+    #[test]
+    fn every_registered_day_is_listed() {
+        let answers = Answers::load(YEAR).unwrap();
+
+        assert_eq!(
+            answers.unlisted(puzzles().into_iter().map(PuzzleId::day)),
+            Vec::<Day>::new()
+        );
+    }
+
+    #[test]
+    fn unlisted_days() {
+        let answers =
+            "[01]\npart_1 = 1\npart_2 = 2\n\n[02]\npart_1 = \"None\"\npart_2 = \"None\"\n"
+                .parse::<Answers>()
+                .unwrap();
+
+        assert_eq!(
+            answers.unlisted([day!(1), day!(2), day!(3), day!(25)]),
+            vec![day!(3), day!(25)]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+use serde_json::from_str as from_json_str;
+
+use super::day::Day;
+use super::puzzle::PuzzleId;
+use super::solution::data_roots;
+use super::timing::{Stats, to_nanos};
+use super::year::Year;
+
+/// The timings of a single part at a given git revision, as saved by `aoc --bench N --save`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub revision: String,
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub std_dev_ns: u64,
+}
+
+impl Baseline {
+    #[must_use]
+    pub fn new(puzzle: PuzzleId, part: u8, revision: &str, runs: u32, stats: &Stats) -> Self {
+        Self {
+            year: puzzle.year().into_inner(),
+            day: puzzle.day().into_inner(),
+            part,
+            revision: revision.into(),
+            runs,
+            min_ns: to_nanos(stats.min),
+            median_ns: to_nanos(stats.median),
+            mean_ns: to_nanos(stats.mean),
+            std_dev_ns: to_nanos(stats.std_dev),
+        }
+    }
+
+    /// Where the baseline of a part at a revision lives, e.g. `<dir>/1a2b3c4/2023-17-2.json`.
+    #[must_use]
+    pub fn path(dir: &Path, revision: &str, puzzle: PuzzleId, part: u8) -> PathBuf {
+        dir.join(revision).join(format!("{}-{}.json", puzzle, part))
+    }
+
+    /// Writes the baseline to `dir`, creating the revision folder when needed.
+    ///
+    /// # Errors
+    /// When the folder or the file cannot be written.
+    pub fn save(&self, dir: &Path) -> Result<PathBuf, BaselineError> {
+        let puzzle = Year::try_new(self.year)
+            .zip(Day::try_new(self.day))
+            .and_then(|(year, day)| PuzzleId::try_new(year, day))
+            .ok_or(BaselineError::InvalidPuzzle(self.year, self.day))?;
+
+        let path = Self::path(dir, &self.revision, puzzle, self.part);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(path)
+    }
+
+    /// Reads the baseline of a part at a revision, or [`None`] when it was never saved.
+    ///
+    /// # Errors
+    /// When the file exists, but cannot be read or parsed.
+    pub fn load(
+        dir: &Path,
+        revision: &str,
+        puzzle: PuzzleId,
+        part: u8,
+    ) -> Result<Option<Self>, BaselineError> {
+        match fs::read_to_string(Self::path(dir, revision, puzzle, part)) {
+            Ok(contents) => Ok(Some(from_json_str(&contents)?)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    /// Compares `median` against our median, returning the slowdown in percent when it exceeds
+    /// `threshold` percent.
+    #[must_use]
+    pub fn regression(&self, median: Duration, threshold: f64) -> Option<f64> {
+        let baseline = self.median().as_secs_f64();
+
+        if baseline <= 0.0 {
+            return None;
+        }
+
+        let slowdown = (median.as_secs_f64() / baseline - 1.0) * 100.0;
+
+        (slowdown > threshold).then_some(slowdown)
+    }
+}
+
+/// The default location of the baselines, in the preferred data folder.
+#[must_use]
+pub fn baselines_dir() -> PathBuf {
+    data_roots()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| PathBuf::from("data"))
+        .join("baselines")
+}
+
+/// The short hash of the checked out git revision, marked `-dirty` when there are local changes.
+#[must_use]
+pub fn current_revision() -> Option<String> {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        String::from_utf8(output.stdout).ok()
+    };
+
+    let revision = git(&["rev-parse", "--short", "HEAD"])?.trim().to_owned();
+
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.trim().is_empty());
+
+    Some(if dirty {
+        format!("{}-dirty", revision)
+    } else {
+        revision
+    })
+}
+
+/// An error which can be returned when saving or loading a [`Baseline`].
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    InvalidPuzzle(u16, u8),
+}
+
+impl From<io::Error> for BaselineError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for BaselineError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl Error for BaselineError {}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Io(ref error) => write!(f, "baseline: {}", error),
+            Self::Json(ref error) => write!(f, "baseline: {}", error),
+            Self::InvalidPuzzle(year, day) => {
+                write!(f, "baseline: no puzzle on day {} of {}", day, year)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+    use std::{env, fs, process};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::puzzle;
+    use crate::shared::baseline::Baseline;
+    use crate::shared::timing::Stats;
+
+    fn baseline(median: Duration) -> Baseline {
+        Baseline::new(
+            puzzle!(2023, 17),
+            2,
+            "abc1234",
+            10,
+            &Stats {
+                min: median,
+                median,
+                mean: median,
+                std_dev: Duration::ZERO,
+            },
+        )
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            Baseline::path(Path::new("/b"), "abc1234", puzzle!(2023, 7), 1),
+            Path::new("/b/abc1234/2023-07-1.json")
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = env::temp_dir().join(format!("aoc-baselines-{}", process::id()));
+
+        let saved = baseline(Duration::from_millis(12));
+
+        saved.save(&dir).unwrap();
+
+        let loaded = Baseline::load(&dir, "abc1234", puzzle!(2023, 17), 2);
+        let missing = Baseline::load(&dir, "abc1234", puzzle!(2023, 17), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.unwrap(), Some(saved));
+        assert_eq!(missing.unwrap(), None);
+    }
+
+    #[test]
+    fn regression() {
+        let baseline = baseline(Duration::from_millis(100));
+
+        assert_eq!(baseline.regression(Duration::from_millis(105), 10.0), None);
+        assert_eq!(baseline.regression(Duration::from_millis(50), 10.0), None);
+        assert_eq!(
+            baseline
+                .regression(Duration::from_millis(125), 10.0)
+                .map(f64::round),
+            Some(25.0)
+        );
+    }
+}
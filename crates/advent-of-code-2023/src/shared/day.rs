@@ -36,7 +36,7 @@ impl Day {
 
     /// Converts the [`Day`] into an [`u8`].
     #[must_use]
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
@@ -0,0 +1,122 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::grids::ParseGridError;
+
+/// An error which can be returned when solving a part.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed. Both `line` and `column` start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// The input is well-formed, but there is no answer to be found in it.
+    NoSolution(String),
+}
+
+impl SolveError {
+    /// Creates a [`SolveError::Parse`] from the 0-based indices of the offending character.
+    #[must_use]
+    pub fn parse_at<R: Into<String>>(line_index: usize, column_index: usize, reason: R) -> Self {
+        Self::Parse {
+            line: line_index + 1,
+            column: column_index + 1,
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a [`SolveError::NoSolution`].
+    #[must_use]
+    pub fn no_solution<R: Into<String>>(reason: R) -> Self {
+        Self::NoSolution(reason.into())
+    }
+}
+
+impl<E: Display> From<ParseGridError<E>> for SolveError {
+    fn from(error: ParseGridError<E>) -> Self {
+        match error {
+            ParseGridError::Empty => Self::parse_at(0, 0, "the grid is empty"),
+            ParseGridError::Cell {
+                row,
+                column,
+                character,
+                error,
+            } => Self::Parse {
+                line: row,
+                column,
+                reason: format!("invalid cell `{}`: {}", character, error),
+            },
+            ParseGridError::Width {
+                row,
+                expected,
+                actual,
+            } => Self::Parse {
+                line: row,
+                column: expected.min(actual) + 1,
+                reason: format!("expected {} cells, found {}", expected, actual),
+            },
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Parse {
+                line,
+                column,
+                ref reason,
+            } => write!(
+                f,
+                "invalid input at line {}, column {}: {}",
+                line, column, reason
+            ),
+            Self::NoSolution(ref reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::error::SolveError;
+    use crate::shared::grids::ParseGridError;
+
+    #[test]
+    fn parse_at_is_1_based() {
+        assert_eq!(
+            SolveError::parse_at(0, 4, "bad"),
+            SolveError::Parse {
+                line: 1,
+                column: 5,
+                reason: "bad".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            SolveError::parse_at(2, 0, "expected a digit").to_string(),
+            "invalid input at line 3, column 1: expected a digit"
+        );
+
+        assert_eq!(
+            SolveError::no_solution("goal unreachable").to_string(),
+            "no solution: goal unreachable"
+        );
+    }
+
+    #[test]
+    fn from_parse_grid_error() {
+        assert_eq!(
+            SolveError::from(ParseGridError::<&str>::Empty).to_string(),
+            "invalid input at line 1, column 1: the grid is empty"
+        );
+    }
+}
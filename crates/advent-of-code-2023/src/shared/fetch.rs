@@ -0,0 +1,396 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use ureq::{Agent, AgentBuilder, Error as HttpError, Transport};
+
+use super::puzzle::PuzzleId;
+use super::solution::data_roots;
+
+/// The environment variable that holds the session cookie of an Advent of Code login.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The environment variable that overrides [`DEFAULT_BASE_URL`].
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Where the puzzles live.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    " (",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")"
+);
+
+/// An agent that identifies us to the server.
+pub(super) fn agent() -> Agent {
+    AgentBuilder::new().user_agent(USER_AGENT).build()
+}
+
+/// The base URL from [`BASE_URL_ENV`], when set.
+pub(super) fn base_url_from_env() -> Option<String> {
+    env::var(BASE_URL_ENV).ok().filter(|url| !url.is_empty())
+}
+
+/// The config file we read the session from when [`SESSION_ENV`] isn't set.
+///
+/// This is `$XDG_CONFIG_HOME/aoc/session`, falling back to `$HOME/.config/aoc/session`.
+#[must_use]
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var_os("HOME")
+                .filter(|d| !d.is_empty())
+                .map(|home| Path::new(&home).join(".config"))
+        })
+        .map(|config| config.join("aoc").join("session"))
+}
+
+/// The session from [`SESSION_ENV`], or else from the [`session_file`].
+#[must_use]
+pub fn session() -> Option<String> {
+    session_from(env::var(SESSION_ENV).ok(), session_file().as_deref())
+}
+
+fn session_from(from_env: Option<String>, file: Option<&Path>) -> Option<String> {
+    let non_blank = |session: String| {
+        let session = session.trim();
+
+        (!session.is_empty()).then(|| session.to_owned())
+    };
+
+    from_env.and_then(non_blank).or_else(|| {
+        file.and_then(|path| fs::read_to_string(path).ok())
+            .and_then(non_blank)
+    })
+}
+
+/// Where an input came from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// We already had the input, at this path.
+    Cached(PathBuf),
+    /// We downloaded the input, and stored it at this path.
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match *self {
+            Self::Cached(ref path) | Self::Downloaded(ref path) => path,
+        }
+    }
+}
+
+/// Downloads puzzle inputs into the data folder.
+///
+/// An input is only downloaded when none of the data roots has it yet, so the server is hit at
+/// most once per puzzle.
+pub struct Fetcher {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    roots: Vec<PathBuf>,
+}
+
+impl Fetcher {
+    /// A fetcher for [`DEFAULT_BASE_URL`] that caches in `roots`, and stores new inputs in the
+    /// first of them.
+    #[must_use]
+    pub fn new<S: Into<String>>(session: S, roots: Vec<PathBuf>) -> Self {
+        Self {
+            agent: agent(),
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+            roots,
+        }
+    }
+
+    /// A fetcher with the [`session`], the base URL from [`BASE_URL_ENV`] when set, caching in
+    /// the [`data_roots`].
+    ///
+    /// # Errors
+    /// When there is no session.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let fetcher = Self::new(session().ok_or(FetchError::NoSession)?, data_roots());
+
+        Ok(match base_url_from_env() {
+            Some(base_url) => fetcher.with_base_url(base_url),
+            None => fetcher,
+        })
+    }
+
+    #[must_use]
+    pub fn with_base_url<U: Into<String>>(mut self, base_url: U) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    fn relative_path(puzzle: PuzzleId) -> PathBuf {
+        Path::new(&puzzle.year().to_string())
+            .join("inputs")
+            .join(format!("{}.txt", puzzle.day()))
+    }
+
+    /// The input of `puzzle` in the first data root that has a non-empty one.
+    #[must_use]
+    pub fn cached(&self, puzzle: PuzzleId) -> Option<PathBuf> {
+        let relative = Self::relative_path(puzzle);
+
+        self.roots
+            .iter()
+            .map(|root| root.join(&relative))
+            .find(|path| fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0))
+    }
+
+    /// Makes sure we have the input of `puzzle`, downloading it when we don't.
+    ///
+    /// # Errors
+    /// When the server doesn't hand out the input, or it cannot be stored.
+    pub fn fetch_input(&self, puzzle: PuzzleId) -> Result<Fetched, FetchError> {
+        if let Some(path) = self.cached(puzzle) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let root = self.roots.first().ok_or(FetchError::NoDataRoot)?;
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            puzzle.year(),
+            puzzle.day().into_inner()
+        );
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        let mut input = String::new();
+        response.into_reader().read_to_string(&mut input)?;
+
+        let path = root.join(Self::relative_path(puzzle));
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // write next to it first, so an interrupted download doesn't leave a partial input behind
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// An error which can be returned when fetching an input.
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    NoDataRoot,
+    Status(u16),
+    Transport(Box<Transport>),
+    Io(io::Error),
+}
+
+impl From<HttpError> for FetchError {
+    fn from(error: HttpError) -> Self {
+        match error {
+            HttpError::Status(status, _) => Self::Status(status),
+            HttpError::Transport(transport) => Self::Transport(Box::new(transport)),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Error for FetchError {}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NoSession => write!(
+                f,
+                "fetch: no session, set {} or write it to {}",
+                SESSION_ENV,
+                session_file().map_or_else(
+                    || String::from("~/.config/aoc/session"),
+                    |path| path.display().to_string()
+                )
+            ),
+            Self::NoDataRoot => write!(f, "fetch: no data folder to store the input in"),
+            Self::Status(400) => write!(f, "fetch: the server rejected the session (400)"),
+            Self::Status(404) => write!(f, "fetch: the puzzle isn't unlocked yet (404)"),
+            Self::Status(status) => write!(f, "fetch: the server responded with {}", status),
+            Self::Transport(ref error) => write!(f, "fetch: {}", error),
+            Self::Io(ref error) => write!(f, "fetch: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead as _, BufReader, Write as _};
+    use std::net::TcpListener;
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use std::{env, fs, process, thread};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::puzzle;
+    use crate::shared::fetch::{FetchError, Fetched, Fetcher, session_from};
+
+    /// A stand-in for the Advent of Code server. Serves `body` for day 1, and 404 for the rest.
+    ///
+    /// Returns its base URL, and the `request line | cookie` of every request it received.
+    fn serve(body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+
+                let mut lines = BufReader::new(&stream)
+                    .lines()
+                    .map_while(Result::ok)
+                    .take_while(|line| !line.is_empty());
+
+                let request_line = lines.next().unwrap_or_default();
+                let cookie = lines
+                    .find_map(|line| line.strip_prefix("Cookie: ").map(str::to_owned))
+                    .unwrap_or_default();
+
+                let (status, body) = if request_line.starts_with("GET /2023/day/1/input ") {
+                    ("200 OK", body)
+                } else {
+                    ("404 Not Found", "")
+                };
+
+                received
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} | {}", request_line, cookie));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+
+        drop(fs::remove_dir_all(&root));
+
+        root
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (base_url, requests) = serve("1abc2\n");
+        let root = temp_root("once");
+
+        let fetcher = Fetcher::new("s3cr3t", vec![root.clone()]).with_base_url(base_url);
+
+        let first = fetcher.fetch_input(puzzle!(2023, 1)).unwrap();
+        let second = fetcher.fetch_input(puzzle!(2023, 1)).unwrap();
+
+        let contents = fs::read_to_string(first.path()).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        let path = root.join("2023/inputs/01.txt");
+
+        assert_eq!(first, Fetched::Downloaded(path.clone()));
+        assert_eq!(second, Fetched::Cached(path));
+        assert_eq!(contents, "1abc2\n");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /2023/day/1/input HTTP/1.1 | session=s3cr3t"]
+        );
+    }
+
+    #[test]
+    fn never_fetches_an_input_we_have() {
+        let (base_url, requests) = serve("new\n");
+        let (empty, cached) = (temp_root("empty"), temp_root("cached"));
+
+        // an empty input, as left behind by `aoc scaffold`, doesn't count
+        fs::create_dir_all(empty.join("2023/inputs")).unwrap();
+        fs::write(empty.join("2023/inputs/01.txt"), "").unwrap();
+        fs::create_dir_all(cached.join("2023/inputs")).unwrap();
+        fs::write(cached.join("2023/inputs/01.txt"), "old\n").unwrap();
+
+        let fetched = Fetcher::new("s3cr3t", vec![empty.clone(), cached.clone()])
+            .with_base_url(base_url)
+            .fetch_input(puzzle!(2023, 1));
+
+        fs::remove_dir_all(&empty).unwrap();
+        fs::remove_dir_all(&cached).unwrap();
+
+        assert_eq!(
+            fetched.unwrap(),
+            Fetched::Cached(cached.join("2023/inputs/01.txt"))
+        );
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn locked_puzzle() {
+        let (base_url, _) = serve("");
+        let root = temp_root("locked");
+
+        let error = Fetcher::new("s3cr3t", vec![root.clone()])
+            .with_base_url(format!("{}/", base_url))
+            .fetch_input(puzzle!(2023, 25))
+            .unwrap_err();
+
+        assert!(matches!(error, FetchError::Status(404)));
+        assert!(!root.join("2023/inputs/25.txt").exists());
+    }
+
+    #[test]
+    fn session_prefers_env() {
+        let file = temp_root("session");
+        fs::write(&file, " from-file\n").unwrap();
+
+        let from_env = session_from(Some("from-env".into()), Some(&file));
+        let from_file = session_from(None, Some(&file));
+        let blank = session_from(Some("  ".into()), Some(&file));
+        let none = session_from(Some("  ".into()), None);
+        let missing = session_from(None, Some(Path::new("/does/not/exist")));
+
+        fs::remove_file(&file).unwrap();
+
+        assert_eq!(from_env.as_deref(), Some("from-env"));
+        assert_eq!(from_file.as_deref(), Some("from-file"));
+        assert_eq!(blank.as_deref(), Some("from-file"));
+        assert_eq!(none, None);
+        assert_eq!(missing, None);
+    }
+}
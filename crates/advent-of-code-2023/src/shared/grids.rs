@@ -1,9 +1,13 @@
 pub mod grid;
 pub mod infinite_grid;
+pub mod view;
 
 use std::cmp::PartialEq;
-use std::ops::Index;
-use std::slice::Iter;
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::iter::Flatten;
+use std::{array, slice};
 
 use hashbrown::HashSet;
 
@@ -61,37 +65,177 @@ type HorizontalVerticalNeighbors<T> = HashSet<((T, T), HorizontalVerticalDirecti
 type HorizontalVerticalDiagonalNeighbors<T> =
     HashSet<((T, T), HorizontalVerticalDiagonalDirection)>;
 
+/// The neighbors of a cell in up to `N` directions, without allocating, skipping the directions
+/// that leave the grid.
+pub type NeighborIter<T, D, const N: usize> = Flatten<array::IntoIter<Option<((T, T), D)>, N>>;
+pub type HorizontalVerticalNeighborIter<T> = NeighborIter<T, HorizontalVerticalDirection, 4>;
+pub type HorizontalVerticalDiagonalNeighborIter<T> =
+    NeighborIter<T, HorizontalVerticalDiagonalDirection, 8>;
+
+/// The offsets, as `(row, column)` deltas, of the cells that count as neighbors of a cell.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Neighborhood {
+    offsets: Vec<(isize, isize)>,
+}
+
+impl Neighborhood {
+    /// Creates a neighborhood from its offsets, which are visited in the given order.
+    #[must_use]
+    pub fn new<I: IntoIterator<Item = (isize, isize)>>(offsets: I) -> Self {
+        Self {
+            offsets: offsets.into_iter().collect(),
+        }
+    }
+
+    /// The von Neumann neighborhood: every cell within a Manhattan distance of `radius`, row
+    /// after row.
+    ///
+    /// # Panics
+    /// When `radius` doesn't fit in an `isize`.
+    #[must_use]
+    pub fn von_neumann(radius: usize) -> Self {
+        Self::square(radius, |(row, column)| {
+            row.unsigned_abs() + column.unsigned_abs() <= radius
+        })
+    }
+
+    /// The Moore neighborhood: every cell within a Chebyshev distance of `radius`, row after row.
+    ///
+    /// # Panics
+    /// When `radius` doesn't fit in an `isize`.
+    #[must_use]
+    pub fn moore(radius: usize) -> Self {
+        Self::square(radius, |_| true)
+    }
+
+    /// The cells a knight can jump to in chess, clockwise from up.
+    #[must_use]
+    pub fn knight() -> Self {
+        Self::new([
+            (-2, 1),
+            (-1, 2),
+            (1, 2),
+            (2, 1),
+            (2, -1),
+            (1, -2),
+            (-1, -2),
+            (-2, -1),
+        ])
+    }
+
+    #[must_use]
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        &self.offsets
+    }
+
+    /// The cells of the square of `radius` around a cell, but the cell itself, that are `kept`.
+    fn square<F: Fn((isize, isize)) -> bool>(radius: usize, kept: F) -> Self {
+        let radius = isize::try_from(radius).expect("Radius too large");
+
+        Self::new(
+            (-radius..=radius)
+                .flat_map(|row| (-radius..=radius).map(move |column| (row, column)))
+                .filter(|&offset| offset != (0, 0) && kept(offset)),
+        )
+    }
+}
+
 pub trait Neighbors {
     type Index: GridIndex;
 
+    /// The horizontal and vertical neighbors of a cell.
+    type HvNeighborsIter: Iterator<Item = ((Self::Index, Self::Index), HorizontalVerticalDirection)>;
+
+    /// The horizontal, vertical, and diagonal neighbors of a cell.
+    type HvdNeighborsIter: Iterator<
+        Item = (
+            (Self::Index, Self::Index),
+            HorizontalVerticalDiagonalDirection,
+        ),
+    >;
+
+    /// Iterates over the horizontal and vertical neighbors, clockwise from up.
+    fn hv_neighbors_iter(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> Self::HvNeighborsIter;
+
+    /// Iterates over the horizontal, vertical, and diagonal neighbors, clockwise from up.
+    fn hvd_neighbors_iter(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> Self::HvdNeighborsIter;
+
+    /// Moves `offset` away from the cell at `row_index` and `column_index`, or [`None`] when that
+    /// leaves the grid.
+    fn step(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+        offset: (isize, isize),
+    ) -> Option<(Self::Index, Self::Index)>;
+
+    /// Iterates over the cells of `neighborhood` around a cell that are on the grid, with the
+    /// offset that reaches each one.
+    fn neighborhood_iter<'g>(
+        &'g self,
+        neighborhood: &'g Neighborhood,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> NeighborhoodIter<'g, Self>
+    where
+        Self: Sized,
+    {
+        NeighborhoodIter {
+            grid: self,
+            offsets: neighborhood.offsets.iter(),
+            row_index,
+            column_index,
+        }
+    }
+
     /// Gets the horizontal and vertical neighbors.
     fn hv_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalNeighbors<Self::Index>;
+    ) -> HorizontalVerticalNeighbors<Self::Index> {
+        self.hv_neighbors_iter(row_index, column_index).collect()
+    }
 
     /// Gets the horizontal, vertical, and diagonal neighbors.
     fn hvd_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalDiagonalNeighbors<Self::Index>;
+    ) -> HorizontalVerticalDiagonalNeighbors<Self::Index> {
+        self.hvd_neighbors_iter(row_index, column_index).collect()
+    }
 }
 
 pub trait GridIter {
-    type GridRow;
+    type Cell;
 
-    fn get_grid(&self) -> &Vec<Self::GridRow>;
     fn get_row_length(&self) -> usize;
     fn get_column_length(&self) -> usize;
 
-    fn row_iter(&self) -> Iter<'_, Self::GridRow> {
-        self.get_grid().iter()
+    /// Gets the cell at `row_index` and `column_index`, or [`None`] outside of the grid.
+    fn get_cell(&self, row_index: usize, column_index: usize) -> Option<&Self::Cell>;
+
+    fn row_iter(&'_ self) -> YIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        YIter::new(self)
     }
 
-    fn y_iter(&'_ self) -> Iter<'_, Self::GridRow> {
-        self.get_grid().iter()
+    fn y_iter(&'_ self) -> YIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        YIter::new(self)
     }
 
     fn x_iter(&'_ self) -> XIter<'_, Self>
@@ -123,11 +267,169 @@ pub trait GridIter {
     }
 }
 
-pub trait GridIndex {}
+/// An error which can be returned when parsing a grid from puzzle text, a cell per character.
+///
+/// Rows and columns start at 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    /// The text has no cells.
+    Empty,
+    /// The character at `row` and `column` isn't a cell, because of `error`.
+    Cell {
+        row: usize,
+        column: usize,
+        character: char,
+        error: E,
+    },
+    /// `row` is `actual` cells wide, but the rows above it are `expected` cells wide.
+    Width {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl<E> ParseGridError<E> {
+    /// Moves the error `rows` rows down, for grids parsed from the middle of a larger text.
+    #[must_use]
+    pub fn offset_rows(self, rows: usize) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Cell {
+                row,
+                column,
+                character,
+                error,
+            } => Self::Cell {
+                row: row + rows,
+                column,
+                character,
+                error,
+            },
+            Self::Width {
+                row,
+                expected,
+                actual,
+            } => Self::Width {
+                row: row + rows,
+                expected,
+                actual,
+            },
+        }
+    }
+}
+
+impl<E: Debug + Display> Error for ParseGridError<E> {}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "grid: no cells"),
+            Self::Cell {
+                row,
+                column,
+                character,
+                ref error,
+            } => write!(
+                f,
+                "grid: row {}, column {}: invalid cell `{}`: {}",
+                row, column, character, error
+            ),
+            Self::Width {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "grid: row {} is {} cells wide, expected {}",
+                row, actual, expected
+            ),
+        }
+    }
+}
+
+/// Parses the lines of `input` into rows of equal width, converting every character to a cell.
+///
+/// Returns the cells row after row, and the width of the rows.
+fn parse_rows<T: TryFrom<char>>(input: &str) -> Result<(Vec<T>, usize), ParseGridError<T::Error>> {
+    let mut cells = vec![];
+    let mut column_len = None;
+
+    for (row_index, line) in input.lines().enumerate() {
+        let start = cells.len();
+
+        for (column_index, character) in line.chars().enumerate() {
+            let cell = T::try_from(character).map_err(|error| ParseGridError::Cell {
+                row: row_index + 1,
+                column: column_index + 1,
+                character,
+                error,
+            })?;
+
+            cells.push(cell);
+        }
+
+        let width = cells.len() - start;
+
+        match column_len {
+            Some(expected) if expected != width => {
+                return Err(ParseGridError::Width {
+                    row: row_index + 1,
+                    expected,
+                    actual: width,
+                });
+            },
+            Some(_) => {},
+            None => column_len = Some(width),
+        }
+    }
+
+    match column_len {
+        Some(column_len) if column_len > 0 => Ok((cells, column_len)),
+        Some(_) | None => Err(ParseGridError::Empty),
+    }
+}
+
+/// Flattens `rows` into their cells, row after row, and the width of the rows.
+///
+/// # Panics
+/// When there are no rows, or they are not equal length.
+fn flatten<T>(rows: Vec<Vec<T>>) -> (Vec<T>, usize) {
+    let column_len = rows.first().map(Vec::len).expect("A grid needs a row");
+
+    assert!(
+        rows.iter().all(|row| row.len() == column_len),
+        "Row length differs"
+    );
+
+    (rows.into_iter().flatten().collect(), column_len)
+}
+
+pub trait GridIndex: Copy + Eq + Hash {}
 
 impl GridIndex for usize {}
 impl GridIndex for isize {}
 
+#[must_use]
+pub struct NeighborhoodIter<'g, G: Neighbors> {
+    grid: &'g G,
+    offsets: slice::Iter<'g, (isize, isize)>,
+    row_index: G::Index,
+    column_index: G::Index,
+}
+
+impl<G: Neighbors> Iterator for NeighborhoodIter<'_, G> {
+    type Item = ((G::Index, G::Index), (isize, isize));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.offsets.by_ref().find_map(|&offset| {
+            self.grid
+                .step(self.row_index, self.column_index, offset)
+                .map(|neighbor| (neighbor, offset))
+        })
+    }
+}
+
 #[must_use]
 pub struct XIter<'g, G> {
     grid: &'g G,
@@ -135,20 +437,13 @@ pub struct XIter<'g, G> {
     column_length: usize,
 }
 
-impl<'g, G> Iterator for XIter<'g, G>
-where
-    G: GridIter,
-    G::GridRow: Index<usize>,
-{
-    type Item = Vec<&'g <G::GridRow as Index<usize>>::Output>;
+impl<'g, G: GridIter> Iterator for XIter<'g, G> {
+    type Item = Vec<&'g G::Cell>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.column_index < self.column_length {
-            let column = self
-                .grid
-                .get_grid()
-                .iter()
-                .map(|row| &row[self.column_index])
+            let column = (0..self.grid.get_row_length())
+                .filter_map(|row_index| self.grid.get_cell(row_index, self.column_index))
                 .collect();
 
             self.column_index += 1;
@@ -170,6 +465,41 @@ impl<'g, G: GridIter> XIter<'g, G> {
     }
 }
 
+#[must_use]
+pub struct YIter<'g, G> {
+    grid: &'g G,
+    row_index: usize,
+    row_length: usize,
+}
+
+impl<'g, G: GridIter> Iterator for YIter<'g, G> {
+    type Item = Vec<&'g G::Cell>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_index < self.row_length {
+            let row = (0..self.grid.get_column_length())
+                .filter_map(|column_index| self.grid.get_cell(self.row_index, column_index))
+                .collect();
+
+            self.row_index += 1;
+
+            Some(row)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'g, G: GridIter> YIter<'g, G> {
+    fn new(grid: &'g G) -> YIter<'g, G> {
+        Self {
+            grid,
+            row_index: 0,
+            row_length: grid.get_row_length(),
+        }
+    }
+}
+
 #[must_use]
 pub struct XYValueIter<'g, G> {
     grid: &'g G,
@@ -179,15 +509,8 @@ pub struct XYValueIter<'g, G> {
     column_length: usize,
 }
 
-impl<G: GridIter> XYValueIter<'_, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    pub fn find<P: Fn(&<G::GridRow as Index<usize>>::Output) -> bool>(
-        &self,
-        predicate: P,
-    ) -> Option<(usize, usize)> {
+impl<G: GridIter> XYValueIter<'_, G> {
+    pub fn find<P: Fn(&G::Cell) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
         // The order here is incorrect, it goes by row by row, which is unexpected for xy (column by column)
         for ((row_index, column_index), v) in self.grid.row_column_index_value_iter() {
             if predicate(v) {
@@ -211,19 +534,13 @@ impl<'g, G: GridIter> XYValueIter<'g, G> {
     }
 }
 
-impl<'g, G> Iterator for XYValueIter<'g, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    type Item = ((usize, usize), &'g <G::GridRow as Index<usize>>::Output);
+impl<'g, G: GridIter> Iterator for XYValueIter<'g, G> {
+    type Item = ((usize, usize), &'g G::Cell);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.column_index < self.column_length {
-            let old = (
-                (self.column_index, self.row_index),
-                &self.grid[self.column_index][self.row_index],
-            );
+            let value = self.grid.get_cell(self.row_index, self.column_index)?;
+            let old = ((self.column_index, self.row_index), value);
 
             // and go next
             if self.row_index + 1 == self.row_length {
@@ -250,15 +567,8 @@ pub struct RowColumnIndexValueIter<'g, G> {
     column_length: usize,
 }
 
-impl<G: GridIter> RowColumnIndexValueIter<'_, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    pub fn find<P: Fn(&<G::GridRow as Index<usize>>::Output) -> bool>(
-        &self,
-        predicate: P,
-    ) -> Option<(usize, usize)> {
+impl<G: GridIter> RowColumnIndexValueIter<'_, G> {
+    pub fn find<P: Fn(&G::Cell) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
         for ((row_index, column_index), v) in self.grid.row_column_index_value_iter() {
             if predicate(v) {
                 return Some((row_index, column_index));
@@ -281,19 +591,13 @@ impl<'g, G: GridIter> RowColumnIndexValueIter<'g, G> {
     }
 }
 
-impl<'g, G> Iterator for RowColumnIndexValueIter<'g, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    type Item = ((usize, usize), &'g <G::GridRow as Index<usize>>::Output);
+impl<'g, G: GridIter> Iterator for RowColumnIndexValueIter<'g, G> {
+    type Item = ((usize, usize), &'g G::Cell);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.row_index < self.row_length {
-            let old = (
-                (self.row_index, self.column_index),
-                &self.grid[self.row_index][self.column_index],
-            );
+            let value = self.grid.get_cell(self.row_index, self.column_index)?;
+            let old = ((self.row_index, self.column_index), value);
 
             // and go next
             if self.column_index + 1 == self.column_length {
@@ -315,6 +619,33 @@ where
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use super::Neighborhood;
+
+    #[test]
+    fn neighborhoods() {
+        assert_eq!(
+            &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::von_neumann(1).offsets()
+        );
+        assert_eq!(
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1)
+            ],
+            Neighborhood::moore(1).offsets()
+        );
+        assert_eq!(12, Neighborhood::von_neumann(2).offsets().len());
+        assert_eq!(24, Neighborhood::moore(2).offsets().len());
+        assert_eq!(8, Neighborhood::knight().offsets().len());
+        assert_eq!(Neighborhood::new([]), Neighborhood::von_neumann(0));
+    }
+
     #[test]
     fn test() {
         let first: Vec<char> = vec!['a', 'b', 'c'];
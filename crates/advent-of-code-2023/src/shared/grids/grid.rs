@@ -1,113 +1,184 @@
-use std::ops::{Deref, DerefMut, Index, IndexMut};
-
-use hashbrown::HashSet;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, ChunksExactMut};
+use std::str::FromStr;
 
+use super::view::{GridView, GridViewMut};
 use super::{
-    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDiagonalNeighbors,
-    HorizontalVerticalDirection, HorizontalVerticalNeighbors, Neighbors,
+    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDiagonalNeighborIter,
+    HorizontalVerticalDirection, HorizontalVerticalNeighborIter, Neighbors, ParseGridError,
+    flatten, parse_rows,
 };
 
+/// A grid of cells, stored row after row in a single vector.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    data: Vec<Row<T>>,
+    data: Vec<T>,
     row_len: usize,
     column_len: usize,
-    // max_row: usize,
-    // max_column: usize,
 }
 
-impl<T> Deref for Grid<T> {
-    type Target = [Row<T>];
+impl<T> Grid<T> {
+    /// Builds a new grid.
+    ///
+    /// # Panics
+    /// When there are no rows, or they are not equal length.
+    #[must_use]
+    pub fn new(data: Vec<Vec<T>>) -> Self {
+        let (cells, column_len) = flatten(data);
 
-    fn deref(&self) -> &[Row<T>] {
-        self.data.as_slice()
+        Self::from_cells(cells, column_len)
     }
-}
 
-impl<T> DerefMut for Grid<T> {
-    fn deref_mut(&mut self) -> &mut [Row<T>] {
-        self.data.as_mut_slice()
-    }
-}
+    /// Builds a grid from its cells, row after row, in rows of `column_len` cells.
+    ///
+    /// # Panics
+    /// When `column_len` is zero, or the cells don't fill the last row.
+    #[must_use]
+    pub fn from_cells(cells: Vec<T>, column_len: usize) -> Self {
+        assert!(
+            column_len > 0 && cells.len().is_multiple_of(column_len),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            column_len
+        );
 
-impl<T: Clone> Clone for Grid<T> {
-    fn clone(&self) -> Self {
         Self {
-            data: self.data.clone(),
-            row_len: self.row_len,
-            column_len: self.column_len,
+            row_len: cells.len() / column_len,
+            data: cells,
+            column_len,
         }
     }
-}
 
-impl<T> Grid<T> {
-    /// Builds a new grid.
+    /// Parses a grid from the lines of `input`, converting every character to a cell.
     ///
-    /// # Panics
-    /// When rows are not equal length.
+    /// # Errors
+    /// When `input` has no cells, a character isn't a cell, or the rows differ in width.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError<T::Error>>
+    where
+        T: TryFrom<char>,
+    {
+        parse_rows(input).map(|(cells, column_len)| Self::from_cells(cells, column_len))
+    }
+
+    /// Gets the cell at `row_index` and `column_index`, or [`None`] outside of the grid.
     #[must_use]
-    #[expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-    pub fn new(data: Vec<Vec<T>>) -> Self {
-        for w in data.array_windows::<2>() {
-            assert_eq!(w[0].len(), w[1].len(), "Row length differs");
-        }
+    pub fn get(&self, row_index: usize, column_index: usize) -> Option<&T> {
+        self.offset(row_index, column_index)
+            .map(|offset| &self.data[offset])
+    }
 
-        let rows = data.len();
-        let columns = data[0].len();
+    /// Gets the cell at `row_index` and `column_index` mutably, or [`None`] outside of the grid.
+    #[must_use]
+    pub fn get_mut(&mut self, row_index: usize, column_index: usize) -> Option<&mut T> {
+        self.offset(row_index, column_index)
+            .map(|offset| &mut self.data[offset])
+    }
 
-        Self {
-            data: data.into_iter().map(|r| Row(r)).collect(),
-            row_len: rows,
-            column_len: columns,
-            // max_row: rows - 1,
-            // max_column: columns - 1,
-        }
+    /// The rows, as slices.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.data.chunks_exact(self.column_len)
     }
-}
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct Row<T>(Vec<T>);
+    /// The rows, as mutable slices.
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.data.chunks_exact_mut(self.column_len)
+    }
 
-impl<T: Clone> std::clone::Clone for Row<T> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
+    /// Every cell, row after row.
+    #[must_use]
+    pub fn cells(&self) -> &[T] {
+        &self.data
     }
-}
 
-impl<T> Index<usize> for Row<T> {
-    type Output = T;
+    /// Every cell mutably, row after row.
+    #[must_use]
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+    /// Sees the grid without copying it, to transpose, rotate or flip it.
+    #[must_use]
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView::new(self)
     }
-}
 
-impl<T> IndexMut<usize> for Row<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+    /// Sees the grid without copying it, like [`Grid::view`], but can change its cells.
+    #[must_use]
+    pub fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        GridViewMut::new(self)
     }
-}
 
-impl<T> Deref for Row<T> {
-    type Target = [T];
+    /// Copies the grid with rows and columns swapped.
+    ///
+    /// # Panics
+    /// When the grid has no rows.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().transpose().to_grid()
+    }
 
-    #[inline]
-    fn deref(&self) -> &[T] {
-        self.0.as_slice()
+    /// Copies the grid turned a quarter clockwise.
+    ///
+    /// # Panics
+    /// When the grid has no rows.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_cw().to_grid()
+    }
+
+    /// Copies the grid turned a quarter counterclockwise.
+    ///
+    /// # Panics
+    /// When the grid has no rows.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    /// Copies the grid mirrored left to right.
+    #[must_use]
+    pub fn flip_h(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_h().to_grid()
     }
-}
 
-impl<T> DerefMut for Row<T> {
-    fn deref_mut(&mut self) -> &mut [T] {
-        self.0.as_mut_slice()
+    /// Copies the grid mirrored top to bottom.
+    #[must_use]
+    pub fn flip_v(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_v().to_grid()
+    }
+
+    /// Where the cell at `row_index` and `column_index` is in `data`.
+    fn offset(&self, row_index: usize, column_index: usize) -> Option<usize> {
+        (row_index < self.row_len && column_index < self.column_len)
+            .then(|| row_index * self.column_len + column_index)
     }
 }
 
-impl<T> GridIter for Grid<T> {
-    type GridRow = Row<T>;
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError<T::Error>;
 
-    fn get_grid(&self) -> &Vec<Self::GridRow> {
-        &self.data
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
     }
+}
+
+impl<T> GridIter for Grid<T> {
+    type Cell = T;
 
     fn get_row_length(&self) -> usize {
         self.row_len
@@ -116,18 +187,38 @@ impl<T> GridIter for Grid<T> {
     fn get_column_length(&self) -> usize {
         self.column_len
     }
+
+    fn get_cell(&self, row_index: usize, column_index: usize) -> Option<&T> {
+        self.get(row_index, column_index)
+    }
 }
 
 impl<T> Neighbors for Grid<T> {
     type Index = usize;
+    type HvNeighborsIter = HorizontalVerticalNeighborIter<usize>;
+    type HvdNeighborsIter = HorizontalVerticalDiagonalNeighborIter<usize>;
 
-    fn hv_neighbors(
+    fn step(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalNeighbors<Self::Index> {
-        let mut neighbors = HashSet::with_capacity(4);
+        offset: (isize, isize),
+    ) -> Option<(Self::Index, Self::Index)> {
+        let row_index = row_index
+            .checked_add_signed(offset.0)
+            .filter(|&row_index| row_index < self.row_len)?;
+        let column_index = column_index
+            .checked_add_signed(offset.1)
+            .filter(|&column_index| column_index < self.column_len)?;
+
+        Some((row_index, column_index))
+    }
 
+    fn hv_neighbors_iter(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> Self::HvNeighborsIter {
         let up = row_index.checked_sub(1);
         let down = {
             let down = row_index + 1;
@@ -142,32 +233,21 @@ impl<T> Neighbors for Grid<T> {
             (right < self.column_len).then_some(right)
         };
 
-        if let Some(up) = up {
-            neighbors.insert(((up, column_index), HorizontalVerticalDirection::Up));
-        }
-
-        if let Some(right) = right {
-            neighbors.insert(((row_index, right), HorizontalVerticalDirection::Right));
-        }
-
-        if let Some(down) = down {
-            neighbors.insert(((down, column_index), HorizontalVerticalDirection::Down));
-        }
-
-        if let Some(left) = left {
-            neighbors.insert(((row_index, left), HorizontalVerticalDirection::Left));
-        }
-
-        neighbors
+        [
+            up.map(|up| ((up, column_index), HorizontalVerticalDirection::Up)),
+            right.map(|right| ((row_index, right), HorizontalVerticalDirection::Right)),
+            down.map(|down| ((down, column_index), HorizontalVerticalDirection::Down)),
+            left.map(|left| ((row_index, left), HorizontalVerticalDirection::Left)),
+        ]
+        .into_iter()
+        .flatten()
     }
 
-    fn hvd_neighbors(
+    fn hvd_neighbors_iter(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalDiagonalNeighbors<Self::Index> {
-        let mut neighbors = HashSet::with_capacity(8);
-
+    ) -> Self::HvdNeighborsIter {
         let up = row_index.checked_sub(1);
         let down = {
             let down = row_index + 1;
@@ -182,48 +262,32 @@ impl<T> Neighbors for Grid<T> {
             (right < self.column_len).then_some(right)
         };
 
-        if let Some(up) = up {
-            neighbors.insert(((up, column_index), HorizontalVerticalDiagonalDirection::Up));
-        }
-
-        if let (Some(up), Some(right)) = (up, right) {
-            neighbors.insert(((up, right), HorizontalVerticalDiagonalDirection::UpRight));
-        }
-
-        if let Some(right) = right {
-            neighbors.insert((
-                (row_index, right),
-                HorizontalVerticalDiagonalDirection::Right,
-            ));
-        }
-
-        if let (Some(down), Some(right)) = (down, right) {
-            neighbors.insert((
-                (down, right),
-                HorizontalVerticalDiagonalDirection::DownRight,
-            ));
-        }
-
-        if let Some(down) = down {
-            neighbors.insert((
-                (down, column_index),
-                HorizontalVerticalDiagonalDirection::Down,
-            ));
-        }
-
-        if let (Some(down), Some(left)) = (down, left) {
-            neighbors.insert(((down, left), HorizontalVerticalDiagonalDirection::DownLeft));
-        }
-
-        if let Some(left) = left {
-            neighbors.insert(((row_index, left), HorizontalVerticalDiagonalDirection::Left));
-        }
-
-        if let (Some(up), Some(left)) = (up, left) {
-            neighbors.insert(((up, left), HorizontalVerticalDiagonalDirection::UpLeft));
-        }
-
-        neighbors
+        [
+            up.map(|up| ((up, column_index), HorizontalVerticalDiagonalDirection::Up)),
+            up.zip(right)
+                .map(|upright| (upright, HorizontalVerticalDiagonalDirection::UpRight)),
+            right.map(|right| {
+                (
+                    (row_index, right),
+                    HorizontalVerticalDiagonalDirection::Right,
+                )
+            }),
+            down.zip(right)
+                .map(|downright| (downright, HorizontalVerticalDiagonalDirection::DownRight)),
+            down.map(|down| {
+                (
+                    (down, column_index),
+                    HorizontalVerticalDiagonalDirection::Down,
+                )
+            }),
+            down.zip(left)
+                .map(|downleft| (downleft, HorizontalVerticalDiagonalDirection::DownLeft)),
+            left.map(|left| ((row_index, left), HorizontalVerticalDiagonalDirection::Left)),
+            up.zip(left)
+                .map(|upleft| (upleft, HorizontalVerticalDiagonalDirection::UpLeft)),
+        ]
+        .into_iter()
+        .flatten()
     }
 }
 
@@ -232,8 +296,8 @@ where
     T: std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{}", t)?;
             }
 
@@ -250,8 +314,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Rows: {}, Columns: {}", self.row_len, self.column_len)?;
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{:?}", t)?;
             }
 
@@ -263,16 +327,40 @@ where
 }
 
 impl<T> Index<usize> for Grid<T> {
-    type Output = Row<T>;
+    type Output = [T];
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        let start = index * self.column_len;
+
+        &self.data[start..start + self.column_len]
     }
 }
 
 impl<T> IndexMut<usize> for Grid<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        let start = index * self.column_len;
+
+        &mut self.data[start..start + self.column_len]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        let (row_index, column_index) = index;
+
+        self.get(row_index, column_index)
+            .expect("Index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let (row_index, column_index) = index;
+
+        self.get_mut(row_index, column_index)
+            .expect("Index out of bounds")
     }
 }
 
@@ -282,11 +370,117 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::Grid;
+    use crate::shared::error::SolveError;
     use crate::shared::grids::{
         GridIter as _, HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection,
-        Neighbors as _,
+        Neighborhood, Neighbors as _, ParseGridError,
     };
 
+    #[derive(Debug, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Cell::Open),
+                '#' => Ok(Cell::Wall),
+                _ => Err("expected `.` or `#`"),
+            }
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let g = Grid::<Cell>::parse(".#.\n##.\n").unwrap();
+
+        assert_eq!(g.get_row_length(), 2);
+        assert_eq!(g.get_column_length(), 3);
+        assert_eq!(g[0][1], Cell::Wall);
+        assert_eq!(g[1][2], Cell::Open);
+
+        let g = "abc\ndef".parse::<Grid<char>>().unwrap();
+
+        assert_eq!(g[1][0], 'd');
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Grid::<Cell>::parse("..\n.?").unwrap_err(),
+            ParseGridError::Cell {
+                row: 2,
+                column: 2,
+                character: '?',
+                error: "expected `.` or `#`",
+            }
+        );
+        assert_eq!(
+            Grid::<Cell>::parse("..\n..\n.\n").unwrap_err(),
+            ParseGridError::Width {
+                row: 3,
+                expected: 2,
+                actual: 1,
+            }
+        );
+        assert_eq!(Grid::<Cell>::parse("").unwrap_err(), ParseGridError::Empty);
+        assert_eq!(
+            Grid::<Cell>::parse("\n").unwrap_err(),
+            ParseGridError::Empty
+        );
+    }
+
+    #[test]
+    fn parse_errors_offset() {
+        assert_eq!(
+            Grid::<Cell>::parse("..\n.?").unwrap_err().offset_rows(3),
+            ParseGridError::Cell {
+                row: 5,
+                column: 2,
+                character: '?',
+                error: "expected `.` or `#`",
+            }
+        );
+        assert_eq!(
+            Grid::<Cell>::parse("..\n.").unwrap_err().offset_rows(3),
+            ParseGridError::Width {
+                row: 5,
+                expected: 2,
+                actual: 1,
+            }
+        );
+        assert_eq!(
+            ParseGridError::<&str>::Empty.offset_rows(3),
+            ParseGridError::Empty
+        );
+    }
+
+    #[test]
+    fn parse_error_messages() {
+        let error = Grid::<Cell>::parse("..\n.?").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "grid: row 2, column 2: invalid cell `?`: expected `.` or `#`"
+        );
+        assert_eq!(
+            SolveError::from(error),
+            SolveError::parse_at(1, 1, "invalid cell `?`: expected `.` or `#`")
+        );
+
+        let error = Grid::<Cell>::parse("..\n...").unwrap_err();
+
+        assert_eq!(error.to_string(), "grid: row 2 is 3 cells wide, expected 2");
+        assert_eq!(
+            SolveError::from(error),
+            SolveError::parse_at(1, 2, "expected 2 cells, found 3")
+        );
+    }
+
     #[test]
     fn rows() {
         let g = Grid::new(vec![
@@ -295,9 +489,35 @@ mod tests {
             vec!['g', 'h', 'i'],
         ]);
 
-        let rows = g.data.iter().collect::<Vec<_>>();
+        let rows = vec![
+            vec![&'a', &'b', &'c'],
+            vec![&'d', &'e', &'f'],
+            vec![&'g', &'h', &'i'],
+        ];
 
         assert_eq!(rows, g.row_iter().collect::<Vec<_>>());
+
+        let slices: [&[char]; 3] = [&['a', 'b', 'c'], &['d', 'e', 'f'], &['g', 'h', 'i']];
+
+        assert_eq!(slices.to_vec(), g.rows().collect::<Vec<_>>());
+        assert_eq!(slices[1], &g[1]);
+    }
+
+    #[test]
+    fn flat() {
+        let mut g = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+
+        assert_eq!(g.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(g[(1, 0)], 'd');
+        assert_eq!(g[1][0], 'd');
+        assert_eq!(g.get(0, 3), None);
+        assert_eq!(g.get(2, 0), None);
+
+        g[(0, 2)] = 'x';
+        g[1][1] = 'y';
+
+        assert_eq!(g.to_string(), "abx\ndyf\n");
+        assert_eq!(Grid::from_cells(vec!['a', 'b', 'x', 'd', 'y', 'f'], 3), g);
     }
 
     #[test]
@@ -342,6 +562,23 @@ mod tests {
         assert_eq!(v, g.row_column_index_value_iter().collect::<Vec<_>>());
     }
 
+    #[test]
+    fn x_y_value_iter() {
+        let g = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+
+        let v = vec![
+            ((0, 0), &'a'),
+            ((0, 1), &'d'),
+            ((1, 0), &'b'),
+            ((1, 1), &'e'),
+            ((2, 0), &'c'),
+            ((2, 1), &'f'),
+        ];
+
+        assert_eq!(v, g.x_y_value_iter().collect::<Vec<_>>());
+        assert_eq!(Some((1, 2)), g.x_y_value_iter().find(|&c| c == 'f'));
+    }
+
     #[test]
     fn hv_neighbors_middle() {
         let g = Grid::new(vec![
@@ -422,4 +659,96 @@ mod tests {
 
         assert_eq!(v, g.hvd_neighbors(0, 0));
     }
+
+    #[test]
+    fn hv_neighbors_iter_order() {
+        let g = Grid::new(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+
+        assert_eq!(
+            vec![
+                ((0, 1), HorizontalVerticalDirection::Up),
+                ((1, 2), HorizontalVerticalDirection::Right),
+                ((2, 1), HorizontalVerticalDirection::Down),
+                ((1, 0), HorizontalVerticalDirection::Left),
+            ],
+            g.hv_neighbors_iter(1, 1).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                ((1, 2), HorizontalVerticalDirection::Up),
+                ((2, 1), HorizontalVerticalDirection::Left),
+            ],
+            g.hv_neighbors_iter(2, 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn hvd_neighbors_iter_order() {
+        let g = Grid::new(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+
+        assert_eq!(
+            vec![
+                ((0, 1), HorizontalVerticalDiagonalDirection::Up),
+                ((0, 2), HorizontalVerticalDiagonalDirection::UpRight),
+                ((1, 2), HorizontalVerticalDiagonalDirection::Right),
+                ((2, 2), HorizontalVerticalDiagonalDirection::DownRight),
+                ((2, 1), HorizontalVerticalDiagonalDirection::Down),
+                ((2, 0), HorizontalVerticalDiagonalDirection::DownLeft),
+                ((1, 0), HorizontalVerticalDiagonalDirection::Left),
+                ((0, 0), HorizontalVerticalDiagonalDirection::UpLeft),
+            ],
+            g.hvd_neighbors_iter(1, 1).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                ((1, 2), HorizontalVerticalDiagonalDirection::Up),
+                ((2, 1), HorizontalVerticalDiagonalDirection::Left),
+                ((1, 1), HorizontalVerticalDiagonalDirection::UpLeft),
+            ],
+            g.hvd_neighbors_iter(2, 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn neighborhood_iter() {
+        let g = Grid::new(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+
+        assert_eq!(
+            vec![((1, 2), (1, 2)), ((2, 1), (2, 1))],
+            g.neighborhood_iter(&Neighborhood::knight(), 0, 0)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            Vec::<((usize, usize), (isize, isize))>::new(),
+            g.neighborhood_iter(&Neighborhood::knight(), 1, 1)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                ((0, 2), (-2, 0)),
+                ((1, 1), (-1, -1)),
+                ((1, 2), (-1, 0)),
+                ((2, 0), (0, -2)),
+                ((2, 1), (0, -1)),
+            ],
+            g.neighborhood_iter(&Neighborhood::von_neumann(2), 2, 2)
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,119 +1,98 @@
-use std::ops::{Deref, DerefMut, Index};
-
-use hashbrown::HashSet;
+use std::array;
+use std::ops::Index;
+use std::slice::ChunksExact;
+use std::str::FromStr;
 
 use super::{
     GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection, Neighbors,
+    ParseGridError, flatten, parse_rows,
 };
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-pub struct InfiniteRow<T>(Vec<T>);
-
-impl<T: Clone> std::clone::Clone for InfiniteRow<T> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
-    }
+/// A grid that repeats infinitely in each direction, stored row after row in a single vector.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InfiniteGrid<T> {
+    data: Vec<T>,
+    row_len: usize,
+    column_len: usize,
 }
 
-impl<T> Index<isize> for InfiniteRow<T> {
-    type Output = T;
-
-    fn index(&self, index: isize) -> &Self::Output {
-        let row_index = index
-            .rem_euclid(self.0.len().try_into().expect("Row too long"))
-            .unsigned_abs();
+impl<T> InfiniteGrid<T> {
+    /// Creates a grid that repeats infinitely in each direction repeating on the data.
+    ///
+    /// # Panics
+    /// When there are no rows, or they are not equal length.
+    #[must_use]
+    pub fn new(data: Vec<Vec<T>>) -> Self {
+        let (cells, column_len) = flatten(data);
 
-        &self.0[row_index]
+        Self::from_cells(cells, column_len)
     }
-}
 
-impl<T> Index<usize> for InfiniteRow<T> {
-    type Output = T;
+    /// Creates a grid from its cells, row after row, in rows of `column_len` cells.
+    ///
+    /// # Panics
+    /// When there are no cells, `column_len` is zero, or the cells don't fill the last row.
+    #[must_use]
+    pub fn from_cells(cells: Vec<T>, column_len: usize) -> Self {
+        assert!(
+            !cells.is_empty() && column_len > 0 && cells.len().is_multiple_of(column_len),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            column_len
+        );
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+        Self {
+            row_len: cells.len() / column_len,
+            data: cells,
+            column_len,
+        }
     }
-}
-
-impl<T> Deref for InfiniteRow<T> {
-    type Target = [T];
 
-    #[inline]
-    fn deref(&self) -> &[T] {
-        self.0.as_slice()
+    /// Parses a grid from the lines of `input`, converting every character to a cell.
+    ///
+    /// # Errors
+    /// When `input` has no cells, a character isn't a cell, or the rows differ in width.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError<T::Error>>
+    where
+        T: TryFrom<char>,
+    {
+        parse_rows(input).map(|(cells, column_len)| Self::from_cells(cells, column_len))
     }
-}
 
-impl<T> DerefMut for InfiniteRow<T> {
-    fn deref_mut(&mut self) -> &mut [T] {
-        self.0.as_mut_slice()
+    /// The rows of a single repetition, as slices.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.data.chunks_exact(self.column_len)
     }
-}
-
-pub struct InfiniteGrid<T> {
-    data: Vec<InfiniteRow<T>>,
-    row_len: usize,
-    column_len: usize,
-    // max_row: usize,
-    // max_column: usize,
-}
-
-impl<T> Deref for InfiniteGrid<T> {
-    type Target = [InfiniteRow<T>];
 
-    fn deref(&self) -> &[InfiniteRow<T>] {
-        self.data.as_slice()
+    /// Every cell of a single repetition, row after row.
+    #[must_use]
+    pub fn cells(&self) -> &[T] {
+        &self.data
     }
-}
 
-impl<T> DerefMut for InfiniteGrid<T> {
-    fn deref_mut(&mut self) -> &mut [InfiniteRow<T>] {
-        self.data.as_mut_slice()
-    }
-}
+    /// Where the cell at `row_index` and `column_index`, wrapped into a single repetition, is in
+    /// `data`.
+    fn offset(&self, row_index: isize, column_index: isize) -> usize {
+        let wrap = |index: isize, len: usize| {
+            index
+                .rem_euclid(len.try_into().expect("Grid too large"))
+                .unsigned_abs()
+        };
 
-impl<T: Clone> Clone for InfiniteGrid<T> {
-    fn clone(&self) -> Self {
-        Self {
-            data: self.data.clone(),
-            row_len: self.row_len,
-            column_len: self.column_len,
-        }
+        wrap(row_index, self.row_len) * self.column_len + wrap(column_index, self.column_len)
     }
 }
 
-impl<T> InfiniteGrid<T> {
-    /// Creates a grid that repeats infinitely in each direction repeating on the data.
-    ///
-    /// # Panics
-    /// When rows are not equal length.
-    #[must_use]
-    #[expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-    pub fn new(data: Vec<Vec<T>>) -> Self {
-        for w in data.array_windows::<2>() {
-            assert_eq!(w[0].len(), w[1].len(), "Row length differs");
-        }
-
-        let rows = data.len();
+impl<T: TryFrom<char>> FromStr for InfiniteGrid<T> {
+    type Err = ParseGridError<T::Error>;
 
-        let columns = data[0].len();
-
-        Self {
-            data: data.into_iter().map(|r| InfiniteRow(r)).collect(),
-            row_len: rows,
-            column_len: columns,
-            // max_row: rows - 1,
-            // max_column: columns - 1,
-        }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
     }
 }
 
 impl<T> GridIter for InfiniteGrid<T> {
-    type GridRow = InfiniteRow<T>;
-
-    fn get_grid(&self) -> &Vec<Self::GridRow> {
-        &self.data
-    }
+    type Cell = T;
 
     fn get_row_length(&self) -> usize {
         self.row_len
@@ -122,16 +101,37 @@ impl<T> GridIter for InfiniteGrid<T> {
     fn get_column_length(&self) -> usize {
         self.column_len
     }
+
+    fn get_cell(&self, row_index: usize, column_index: usize) -> Option<&T> {
+        (row_index < self.row_len && column_index < self.column_len)
+            .then(|| &self.data[row_index * self.column_len + column_index])
+    }
 }
 
 impl<T> Neighbors for InfiniteGrid<T> {
     type Index = isize;
+    // every neighbor is on an infinite grid, so there is nothing to skip
+    type HvNeighborsIter = array::IntoIter<((isize, isize), HorizontalVerticalDirection), 4>;
+    type HvdNeighborsIter =
+        array::IntoIter<((isize, isize), HorizontalVerticalDiagonalDirection), 8>;
 
-    fn hv_neighbors(
+    fn step(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HashSet<((Self::Index, Self::Index), HorizontalVerticalDirection)> {
+        offset: (isize, isize),
+    ) -> Option<(Self::Index, Self::Index)> {
+        Some((
+            row_index.checked_add(offset.0)?,
+            column_index.checked_add(offset.1)?,
+        ))
+    }
+
+    fn hv_neighbors_iter(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> Self::HvNeighborsIter {
         [
             (
                 (row_index - 1, column_index),
@@ -151,17 +151,13 @@ impl<T> Neighbors for InfiniteGrid<T> {
             ),
         ]
         .into_iter()
-        .collect()
     }
 
-    fn hvd_neighbors(
+    fn hvd_neighbors_iter(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HashSet<(
-        (Self::Index, Self::Index),
-        HorizontalVerticalDiagonalDirection,
-    )> {
+    ) -> Self::HvdNeighborsIter {
         [
             (
                 (row_index - 1, column_index),
@@ -197,7 +193,6 @@ impl<T> Neighbors for InfiniteGrid<T> {
             ),
         ]
         .into_iter()
-        .collect()
     }
 }
 
@@ -206,8 +201,8 @@ where
     T: std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{}", t)?;
             }
 
@@ -224,8 +219,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Rows: {}, Columns: {}", self.row_len, self.column_len)?;
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{:?}", t)?;
             }
 
@@ -237,24 +232,32 @@ where
 }
 
 impl<T> Index<usize> for InfiniteGrid<T> {
-    type Output = InfiniteRow<T>;
+    type Output = [T];
 
     fn index(&self, index: usize) -> &Self::Output {
-        let row_index = index.rem_euclid(self.row_len);
+        let start = index.rem_euclid(self.row_len) * self.column_len;
 
-        &self.data[row_index]
+        &self.data[start..start + self.column_len]
     }
 }
 
 impl<T> Index<isize> for InfiniteGrid<T> {
-    type Output = InfiniteRow<T>;
+    type Output = [T];
 
     fn index(&self, index: isize) -> &Self::Output {
-        let row_index = index
-            .rem_euclid(self.row_len.try_into().expect("row_count too large"))
-            .unsigned_abs();
+        let start = self.offset(index, 0);
 
-        &self.data[row_index]
+        &self.data[start..start + self.column_len]
+    }
+}
+
+impl<T> Index<(isize, isize)> for InfiniteGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        let (row_index, column_index) = index;
+
+        &self.data[self.offset(row_index, column_index)]
     }
 }
 
@@ -265,9 +268,25 @@ mod tests {
 
     use crate::shared::grids::infinite_grid::InfiniteGrid;
     use crate::shared::grids::{
-        HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection, Neighbors as _,
+        HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection, Neighborhood,
+        Neighbors as _, ParseGridError,
     };
 
+    #[test]
+    fn parse() {
+        let g = "abc\ndef".parse::<InfiniteGrid<char>>().unwrap();
+
+        assert_eq!('f', g[(-1, -1)]);
+        assert_eq!(
+            "abc\nde".parse::<InfiniteGrid<char>>().unwrap_err(),
+            ParseGridError::Width {
+                row: 2,
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
     #[test]
     fn infinite_grid() {
         let g = InfiniteGrid::new(vec![
@@ -276,9 +295,11 @@ mod tests {
             vec!['g', 'h', 'i'],
         ]);
 
-        assert_eq!('b', g[-9_isize][-5_isize]);
+        assert_eq!('b', g[(-9, -5)]);
 
-        assert_eq!('i', g[8_isize][8_isize]);
+        assert_eq!('i', g[(8, 8)]);
+
+        assert_eq!(['g', 'h', 'i'], g[-1_isize]);
     }
 
     #[test]
@@ -368,4 +389,52 @@ mod tests {
 
         assert_eq!(v, g.hvd_neighbors(0, 0));
     }
+
+    #[test]
+    fn hv_neighbors_iter_order() {
+        let g = InfiniteGrid::new(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+
+        assert_eq!(
+            vec![
+                ((-1, 0), HorizontalVerticalDirection::Up),
+                ((0, 1), HorizontalVerticalDirection::Right),
+                ((1, 0), HorizontalVerticalDirection::Down),
+                ((0, -1), HorizontalVerticalDirection::Left),
+            ],
+            g.hv_neighbors_iter(0, 0).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn neighborhood_iter() {
+        let g = InfiniteGrid::new(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+
+        assert_eq!(
+            vec![
+                ((-2, 1), (-2, 1)),
+                ((-1, 2), (-1, 2)),
+                ((1, 2), (1, 2)),
+                ((2, 1), (2, 1)),
+                ((2, -1), (2, -1)),
+                ((1, -2), (1, -2)),
+                ((-1, -2), (-1, -2)),
+                ((-2, -1), (-2, -1)),
+            ],
+            g.neighborhood_iter(&Neighborhood::knight(), 0, 0)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            24,
+            g.neighborhood_iter(&Neighborhood::moore(2), -7, 4).count()
+        );
+    }
 }
@@ -0,0 +1,343 @@
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+use super::GridIter;
+use super::grid::Grid;
+
+/// How a view sees its grid: which way its rows go, and whether they are the grid's columns.
+#[derive(Clone, Copy)]
+struct Orientation {
+    /// Whether rows of the view are columns of the grid.
+    transposed: bool,
+    /// Whether the rows of the grid are seen bottom to top.
+    rows_reversed: bool,
+    /// Whether the columns of the grid are seen right to left.
+    columns_reversed: bool,
+}
+
+impl Orientation {
+    const IDENTITY: Self = Self {
+        transposed: false,
+        rows_reversed: false,
+        columns_reversed: false,
+    };
+
+    fn transpose(self) -> Self {
+        Self {
+            transposed: !self.transposed,
+            ..self
+        }
+    }
+
+    fn flip_h(self) -> Self {
+        if self.transposed {
+            Self {
+                rows_reversed: !self.rows_reversed,
+                ..self
+            }
+        } else {
+            Self {
+                columns_reversed: !self.columns_reversed,
+                ..self
+            }
+        }
+    }
+
+    fn flip_v(self) -> Self {
+        if self.transposed {
+            Self {
+                columns_reversed: !self.columns_reversed,
+                ..self
+            }
+        } else {
+            Self {
+                rows_reversed: !self.rows_reversed,
+                ..self
+            }
+        }
+    }
+
+    /// The lengths of the view of a grid of `row_len` rows and `column_len` columns.
+    fn lengths(self, row_len: usize, column_len: usize) -> (usize, usize) {
+        if self.transposed {
+            (column_len, row_len)
+        } else {
+            (row_len, column_len)
+        }
+    }
+
+    /// Maps a cell of the view to the cell of the grid, or [`None`] outside of it.
+    fn locate<T>(
+        self,
+        grid: &Grid<T>,
+        row_index: usize,
+        column_index: usize,
+    ) -> Option<(usize, usize)> {
+        let (row_index, column_index) = if self.transposed {
+            (column_index, row_index)
+        } else {
+            (row_index, column_index)
+        };
+
+        let row_len = grid.get_row_length();
+        let column_len = grid.get_column_length();
+
+        if row_index >= row_len || column_index >= column_len {
+            return None;
+        }
+
+        let row_index = if self.rows_reversed {
+            row_len - 1 - row_index
+        } else {
+            row_index
+        };
+
+        let column_index = if self.columns_reversed {
+            column_len - 1 - column_index
+        } else {
+            column_index
+        };
+
+        Some((row_index, column_index))
+    }
+}
+
+/// A [`Grid`] seen transposed, rotated or flipped, without copying its cells.
+///
+/// Transforms compose, so `view.rotate_cw().flip_h()` is again a view of the same grid. `R` is how
+/// the view holds the grid: [`GridView`] borrows it, [`GridViewMut`] can also change its cells.
+#[derive(Clone, Copy)]
+pub struct View<R> {
+    grid: R,
+    orientation: Orientation,
+}
+
+/// A [`View`] of a borrowed [`Grid`].
+pub type GridView<'g, T> = View<&'g Grid<T>>;
+
+/// A [`View`] that changes the cells of the [`Grid`] it sees.
+pub type GridViewMut<'g, T> = View<&'g mut Grid<T>>;
+
+impl<T, R: Deref<Target = Grid<T>>> View<R> {
+    /// Sees `grid` as it is.
+    #[must_use]
+    pub fn new(grid: R) -> Self {
+        Self {
+            grid,
+            orientation: Orientation::IDENTITY,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring over the diagonal from the top left.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        Self {
+            orientation: self.orientation.transpose(),
+            ..self
+        }
+    }
+
+    /// Turns a quarter clockwise, so the left column becomes the top row.
+    #[must_use]
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    /// Turns a quarter counterclockwise, so the top row becomes the left column.
+    #[must_use]
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    /// Mirrors left to right.
+    #[must_use]
+    pub fn flip_h(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_h(),
+            ..self
+        }
+    }
+
+    /// Mirrors top to bottom.
+    #[must_use]
+    pub fn flip_v(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_v(),
+            ..self
+        }
+    }
+
+    /// Gets the cell at `row_index` and `column_index` of the view, or [`None`] outside of it.
+    #[must_use]
+    pub fn get(&self, row_index: usize, column_index: usize) -> Option<&T> {
+        let (row_index, column_index) =
+            self.orientation
+                .locate(&self.grid, row_index, column_index)?;
+
+        self.grid.get(row_index, column_index)
+    }
+
+    /// Gets the cell at `row_index` and `column_index` of the view mutably, or [`None`] outside of
+    /// it.
+    #[must_use]
+    pub fn get_mut(&mut self, row_index: usize, column_index: usize) -> Option<&mut T>
+    where
+        R: DerefMut,
+    {
+        let (row_index, column_index) =
+            self.orientation
+                .locate(&self.grid, row_index, column_index)?;
+
+        self.grid.get_mut(row_index, column_index)
+    }
+
+    /// Copies the cells, as seen, into a new grid.
+    ///
+    /// # Panics
+    /// When the view has no columns, as a transposed grid without rows does.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let column_len = self.get_column_length();
+
+        let cells = (0..self.get_row_length())
+            .flat_map(|row_index| {
+                (0..column_len).filter_map(move |column_index| self.get(row_index, column_index))
+            })
+            .cloned()
+            .collect();
+
+        Grid::from_cells(cells, column_len)
+    }
+}
+
+impl<T, R: Deref<Target = Grid<T>>> GridIter for View<R> {
+    type Cell = T;
+
+    fn get_row_length(&self) -> usize {
+        let (row_len, _) = self
+            .orientation
+            .lengths(self.grid.get_row_length(), self.grid.get_column_length());
+
+        row_len
+    }
+
+    fn get_column_length(&self) -> usize {
+        let (_, column_len) = self
+            .orientation
+            .lengths(self.grid.get_row_length(), self.grid.get_column_length());
+
+        column_len
+    }
+
+    fn get_cell(&self, row_index: usize, column_index: usize) -> Option<&T> {
+        self.get(row_index, column_index)
+    }
+}
+
+impl<T, R: Deref<Target = Grid<T>>> Index<(usize, usize)> for View<R> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).expect("Index out of bounds")
+    }
+}
+
+impl<T, R: DerefMut<Target = Grid<T>>> IndexMut<(usize, usize)> for View<R> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index.0, index.1).expect("Index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::grids::GridIter as _;
+    use crate::shared::grids::grid::Grid;
+
+    fn grid() -> Grid<char> {
+        Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']])
+    }
+
+    fn rows(grid: &Grid<char>) -> Vec<String> {
+        grid.rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn transforms() {
+        let g = grid();
+
+        assert_eq!(vec!["ad", "be", "cf"], rows(&g.transpose()));
+        assert_eq!(vec!["da", "eb", "fc"], rows(&g.rotate_cw()));
+        assert_eq!(vec!["cf", "be", "ad"], rows(&g.rotate_ccw()));
+        assert_eq!(vec!["cba", "fed"], rows(&g.flip_h()));
+        assert_eq!(vec!["def", "abc"], rows(&g.flip_v()));
+    }
+
+    #[test]
+    fn compositions() {
+        let g = grid();
+        let view = g.view();
+
+        assert_eq!(rows(&g), rows(&view.rotate_cw().rotate_ccw().to_grid()));
+        assert_eq!(
+            rows(&g),
+            rows(
+                &view
+                    .rotate_cw()
+                    .rotate_cw()
+                    .rotate_cw()
+                    .rotate_cw()
+                    .to_grid()
+            )
+        );
+        assert_eq!(
+            rows(&g.flip_h().flip_v()),
+            rows(&view.rotate_cw().rotate_cw().to_grid())
+        );
+        assert_eq!(
+            rows(&g.transpose().flip_v()),
+            rows(&view.flip_h().transpose().to_grid())
+        );
+        assert_eq!(rows(&g.rotate_cw().transpose()), rows(&g.flip_v()));
+    }
+
+    #[test]
+    fn view() {
+        let g = grid();
+        let view = g.view().rotate_cw();
+
+        assert_eq!(3, view.get_row_length());
+        assert_eq!(2, view.get_column_length());
+        assert_eq!('d', view[(0, 0)]);
+        assert_eq!('c', view[(2, 1)]);
+        assert_eq!(None, view.get(2, 2));
+        assert_eq!(None, view.get(3, 0));
+        assert_eq!(
+            vec![vec![&'d', &'a'], vec![&'e', &'b'], vec![&'f', &'c']],
+            view.row_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn view_mut() {
+        let mut g = grid();
+
+        {
+            let mut view = g.view_mut().rotate_cw();
+
+            assert_eq!(3, view.get_row_length());
+            assert_eq!(2, view.get_column_length());
+            assert_eq!('d', view[(0, 0)]);
+
+            view[(0, 0)] = 'x';
+            view[(2, 1)] = 'y';
+
+            assert_eq!(None, view.get_mut(2, 2));
+        }
+
+        assert_eq!(vec!["aby", "xef"], rows(&g));
+    }
+}
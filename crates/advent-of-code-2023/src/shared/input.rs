@@ -0,0 +1,276 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Read as _;
+use std::path::{Component, Path, PathBuf};
+use std::{fs, io};
+
+use super::answers::{Answers, AnswersError};
+use super::puzzle::PuzzleId;
+use super::solution::{ReadFileError, try_read_data_file};
+use super::year::Year;
+
+/// The argument that selects standard input, rather than a file.
+pub const STDIN: &str = "-";
+
+/// Where the runner reads the input of a puzzle from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `YYYY/inputs/DD.txt` in the data folder, the input the answers manifest is for.
+    #[default]
+    CheckedIn,
+    /// `YYYY/inputs/<name>/DD.txt` in the data folder, e.g. the input of another account.
+    Profile(String),
+    /// A single file, anywhere.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Selects standard input for [`STDIN`], and a file otherwise.
+    #[must_use]
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == STDIN {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Selects the inputs of the profile `name`.
+    ///
+    /// Returns [`None`] when `name` isn't a plain folder name, so a profile can't point outside of
+    /// the inputs folder.
+    #[must_use]
+    pub fn profile(name: &str) -> Option<Self> {
+        let mut components = Path::new(name).components();
+
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(_)), None) => Some(Self::Profile(name.to_owned())),
+            (Some(_) | None, _) => None,
+        }
+    }
+
+    /// Whether the input is one of the files a profile or the checked-in inputs have for every
+    /// puzzle, rather than a single file that only makes sense for a single puzzle.
+    #[must_use]
+    pub fn is_per_puzzle(&self) -> bool {
+        match *self {
+            Self::CheckedIn | Self::Profile(_) => true,
+            Self::File(_) | Self::Stdin => false,
+        }
+    }
+
+    /// Reads the input of `puzzle`.
+    ///
+    /// # Errors
+    /// When the input cannot be read, or when the data folder only has the empty file that
+    /// `aoc scaffold` leaves.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, InputError> {
+        match *self {
+            Self::CheckedIn => read_per_puzzle(per_puzzle(puzzle, None)),
+            Self::Profile(ref name) => read_per_puzzle(per_puzzle(puzzle, Some(name))),
+            Self::File(ref path) => {
+                fs::read_to_string(path).map_err(|error| InputError::File(path.clone(), error))
+            },
+            Self::Stdin => {
+                let mut input = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(input)
+            },
+        }
+    }
+
+    /// The answers we expect for the inputs of `year`.
+    ///
+    /// The checked-in inputs use the answers manifest of their year, and a profile the manifest in
+    /// its own folder, when it has one. We don't know the answers of anything else.
+    ///
+    /// # Errors
+    /// When a manifest exists, but cannot be read or is malformed.
+    pub fn answers(&self, year: Year) -> Result<Answers, AnswersError> {
+        match *self {
+            Self::CheckedIn => Answers::load(year),
+            Self::Profile(ref name) => Answers::load_profile(year, name),
+            Self::File(_) | Self::Stdin => Ok(Answers::default()),
+        }
+    }
+}
+
+/// `YYYY/inputs/DD.txt`, or `YYYY/inputs/<profile>/DD.txt`.
+fn per_puzzle(puzzle: PuzzleId, profile: Option<&str>) -> PathBuf {
+    let mut path = Path::new(&puzzle.year().to_string()).join("inputs");
+
+    path.extend(profile);
+    path.push(format!("{}.txt", puzzle.day()));
+
+    path
+}
+
+fn read_per_puzzle(path: PathBuf) -> Result<String, InputError> {
+    let input = try_read_data_file(&path)?;
+
+    if input.is_empty() {
+        return Err(InputError::Empty(path));
+    }
+
+    Ok(input)
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::CheckedIn => write!(f, "inputs"),
+            Self::Profile(ref name) => write!(f, "{}", name),
+            Self::File(ref path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// An error which can be returned when reading an input from an [`InputSource`].
+#[derive(Debug)]
+pub enum InputError {
+    Read(ReadFileError),
+    /// The file in the data folder is empty, like a new scaffold leaves it.
+    Empty(PathBuf),
+    File(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl From<ReadFileError> for InputError {
+    fn from(error: ReadFileError) -> Self {
+        Self::Read(error)
+    }
+}
+
+impl InputError {
+    /// Whether the data folders have no input for the puzzle, rather than failing to read it.
+    ///
+    /// An empty file in the data folders is missing too: `aoc scaffold` leaves one for every new
+    /// puzzle. A file given explicitly that doesn't exist is an error, never a missing input.
+    #[must_use]
+    pub fn is_missing(&self) -> bool {
+        match *self {
+            Self::Read(ref error) => error.is_not_found(),
+            Self::Empty(_) => true,
+            Self::File(..) | Self::Stdin(_) => false,
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Read(ref error) => write!(f, "input: {}", error),
+            Self::Empty(ref path) => write!(f, "input: {}: the file is empty", path.display()),
+            Self::File(ref path, ref error) => write!(f, "input: {}: {}", path.display(), error),
+            Self::Stdin(ref error) => write!(f, "input: stdin: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use pretty_assertions::assert_eq;
+
+    use super::{InputError, InputSource, per_puzzle};
+    use crate::shared::PartSolution;
+    use crate::shared::puzzle::PuzzleId;
+
+    fn puzzle() -> PuzzleId {
+        "2023-06".parse().unwrap()
+    }
+
+    #[test]
+    fn from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("in/06.txt"),
+            InputSource::File(PathBuf::from("in/06.txt"))
+        );
+    }
+
+    #[test]
+    fn profiles_stay_in_the_inputs_folder() {
+        assert_eq!(
+            InputSource::profile("alice"),
+            Some(InputSource::Profile(String::from("alice")))
+        );
+        assert_eq!(InputSource::profile(""), None);
+        assert_eq!(InputSource::profile(".."), None);
+        assert_eq!(InputSource::profile("alice/bob"), None);
+        assert_eq!(InputSource::profile("/alice"), None);
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            per_puzzle(puzzle(), None),
+            PathBuf::from("2023/inputs/06.txt")
+        );
+        assert_eq!(
+            per_puzzle(puzzle(), Some("alice")),
+            PathBuf::from("2023/inputs/alice/06.txt")
+        );
+    }
+
+    #[test]
+    fn read_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/2023/examples/06.txt");
+
+        let input = InputSource::File(path).read(puzzle()).unwrap();
+
+        assert_eq!(input.lines().count(), 2);
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let error = InputSource::from_arg("/does/not/exist.txt")
+            .read(puzzle())
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("input: /does/not/exist.txt: ")
+        );
+    }
+
+    #[test]
+    fn missing_inputs() {
+        let profile = InputSource::profile("nobody").unwrap();
+
+        assert!(profile.read(puzzle()).unwrap_err().is_missing());
+        assert!(
+            !InputSource::from_arg("/does/not/exist.txt")
+                .read(puzzle())
+                .unwrap_err()
+                .is_missing()
+        );
+        assert!(InputError::Empty(per_puzzle(puzzle(), None)).is_missing());
+    }
+
+    #[test]
+    fn unknown_answers() {
+        let none = [PartSolution::None, PartSolution::None];
+
+        let answers = |source: InputSource| {
+            source
+                .answers(puzzle().year())
+                .unwrap()
+                .take(puzzle().day())
+        };
+
+        assert_eq!(answers(InputSource::Stdin), none);
+        assert_eq!(answers(InputSource::profile("nobody").unwrap()), none);
+    }
+}
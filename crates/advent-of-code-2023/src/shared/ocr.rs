@@ -0,0 +1,401 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::PartSolution;
+use super::grids::grid::Grid;
+
+/// A block-letter font, as the puzzles draw it.
+struct Font {
+    height: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The 4 by 6 pixel font most puzzles use.
+const SMALL: Font = Font {
+    height: 6,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The 6 by 10 pixel font of the puzzles with larger letters.
+const LARGE: Font = Font {
+    height: 10,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Reads the letters drawn by `pixels`, in the [`SMALL`] or the [`LARGE`] font.
+///
+/// Blank rows above and below the letters are ignored, and letters are separated by blank columns.
+///
+/// # Errors
+/// When the letters have neither height, or one of them isn't in the font.
+pub fn recognize(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    let lit_rows = pixels
+        .iter()
+        .enumerate()
+        .filter(|&(_, row)| row.contains(&true))
+        .map(|(row_index, _)| row_index)
+        .collect::<Vec<_>>();
+
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Blank);
+    };
+
+    let rows = pixels
+        .iter()
+        .skip(top)
+        .take(bottom - top + 1)
+        .collect::<Vec<_>>();
+
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == rows.len())
+        .ok_or(OcrError::Height(rows.len()))?;
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let is_lit = |row: &Vec<bool>, column_index| row.get(column_index).copied().unwrap_or(false);
+    let is_blank = |column_index| rows.iter().all(|&row| !is_lit(row, column_index));
+
+    let mut letters = String::new();
+    let mut column_index = 0;
+
+    while column_index < width {
+        if is_blank(column_index) {
+            column_index += 1;
+
+            continue;
+        }
+
+        let start = column_index;
+
+        while column_index < width && !is_blank(column_index) {
+            column_index += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|&row| (start..column_index).map(|c| is_lit(row, c)).collect())
+            .collect::<Vec<Vec<bool>>>();
+
+        let letter = font
+            .glyphs
+            .iter()
+            .find(|&&(_, art)| trim_columns(&parse_art(art)) == glyph)
+            .map(|&(letter, _)| letter)
+            .ok_or(OcrError::UnknownGlyph { column: start })?;
+
+        letters.push(letter);
+    }
+
+    Ok(letters)
+}
+
+/// Reads the letters drawn by `lines`, in which `#` and `█` are lit pixels.
+///
+/// Unrecognized letters fall back to a [`PartSolution::Manual`] with the lines, for a human to read.
+#[must_use]
+pub fn recognize_lines<S: AsRef<str>>(lines: &[S]) -> PartSolution {
+    let pixels = parse_art(lines);
+
+    recognize(&pixels).map_or_else(|_| manual(&pixels), PartSolution::String)
+}
+
+/// Reads the letters drawn by the lit pixels of `grid`.
+///
+/// Unrecognized letters fall back to a [`PartSolution::Manual`] with the drawing, for a human to
+/// read.
+#[must_use]
+pub fn recognize_grid(grid: &Grid<bool>) -> PartSolution {
+    let pixels = grid.rows().map(<[bool]>::to_vec).collect::<Vec<_>>();
+
+    recognize(&pixels).map_or_else(|_| manual(&pixels), PartSolution::String)
+}
+
+fn parse_art<S: AsRef<str>>(lines: &[S]) -> Vec<Vec<bool>> {
+    lines
+        .iter()
+        .map(|line| {
+            line.as_ref()
+                .chars()
+                .map(|c| matches!(c, '#' | '\u{2588}'))
+                .collect()
+        })
+        .collect()
+}
+
+/// Removes the blank columns left and right of a glyph, as the fonts pad some narrow letters.
+fn trim_columns(glyph: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let width = glyph.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |column_index| glyph.iter().any(|row| row.get(column_index) == Some(&true));
+
+    let Some(first) = (0..width).find(|&c| lit(c)) else {
+        return vec![vec![]; glyph.len()];
+    };
+    let last = (0..width).rev().find(|&c| lit(c)).unwrap_or(first);
+
+    glyph
+        .iter()
+        .map(|row| {
+            (first..=last)
+                .map(|c| row.get(c).copied().unwrap_or(false))
+                .collect()
+        })
+        .collect()
+}
+
+/// The drawing, with `#` for lit pixels and `.` for the others, for a human to read.
+fn manual(pixels: &[Vec<bool>]) -> PartSolution {
+    PartSolution::Manual(
+        pixels
+            .iter()
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+            .collect(),
+    )
+}
+
+/// An error which can be returned when reading letters with [`recognize`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// No pixel is lit.
+    Blank,
+    /// The letters are this many pixels high, which no font is.
+    Height(usize),
+    /// The letter that starts at this column isn't in the font.
+    UnknownGlyph { column: usize },
+}
+
+impl Error for OcrError {}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Blank => write!(f, "ocr: nothing is drawn"),
+            Self::Height(height) => write!(f, "ocr: no font is {} pixels high", height),
+            Self::UnknownGlyph { column } => {
+                write!(f, "ocr: unknown letter at column {}", column + 1)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{OcrError, parse_art, recognize, recognize_grid, recognize_lines};
+    use crate::shared::PartSolution;
+    use crate::shared::grids::grid::Grid;
+
+    #[test]
+    fn small_font() {
+        let lines = [
+            "#..#.####.###..#....###..",
+            "#..#.#....#..#.#....#..#.",
+            "####.###..#..#.#....#..#.",
+            "#..#.#....###..#....###..",
+            "#..#.#....#.#..#....#....",
+            "#..#.####.#..#.####.#....",
+        ];
+
+        assert_eq!(recognize_lines(&lines), PartSolution::from("HERLP"));
+    }
+
+    #[test]
+    fn narrow_and_wide_letters() {
+        let lines = [
+            ".###.#...#..##.",
+            "..#..#...#.#..#",
+            "..#...#.#..#...",
+            "..#....#...#.##",
+            "..#....#...#..#",
+            ".###...#....###",
+        ];
+
+        assert_eq!(recognize(&parse_art(&lines)), Ok(String::from("IYG")));
+    }
+
+    #[test]
+    fn large_font() {
+        let lines = [
+            "",
+            "#....#..#####.",
+            "##...#..#....#",
+            "##...#..#....#",
+            "#.#..#..#....#",
+            "#.#..#..#####.",
+            "#..#.#..#.....",
+            "#..#.#..#.....",
+            "#...##..#.....",
+            "#...##..#.....",
+            "#....#..#.....",
+            "",
+        ];
+
+        assert_eq!(recognize_lines(&lines), PartSolution::from("NP"));
+    }
+
+    #[test]
+    fn grid() {
+        let grid = Grid::new(
+            parse_art(&[
+                " ##  #### ",
+                "#  # #    ",
+                "#  # ###  ",
+                "#### #    ",
+                "#  # #    ",
+                "#  # #    ",
+            ])
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(recognize_grid(&grid), PartSolution::from("AF"));
+    }
+
+    #[test]
+    fn unknown_letters_need_a_human() {
+        let lines = [
+            ".##..#...#",
+            "#..#.##.##",
+            "#..#.#.#.#",
+            "####.#...#",
+            "#..#.#...#",
+            "#..#.#...#",
+        ];
+
+        assert_eq!(
+            recognize(&parse_art(&lines)),
+            Err(OcrError::UnknownGlyph { column: 5 })
+        );
+        assert_eq!(
+            recognize_lines(&lines),
+            PartSolution::Manual(lines.iter().map(|&line| String::from(line)).collect())
+        );
+        assert_eq!(recognize(&parse_art(&["#", "#"])), Err(OcrError::Height(2)));
+        assert_eq!(recognize(&parse_art(&["...", ""])), Err(OcrError::Blank));
+    }
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::day::Day;
+use super::year::Year;
+
+/// A single puzzle, i.e. a [`Day`] of a [`Year`] that had a puzzle on that day.
+///
+/// # Display
+/// This value displays as `YYYY-DD`.
+///
+/// ```
+/// # use advent_of_code_2023::{day, year};
+/// # use advent_of_code_2023::shared::puzzle::PuzzleId;
+/// const PUZZLE: PuzzleId = PuzzleId::try_new(year!(2023), day!(8)).unwrap();
+/// assert_eq!(PUZZLE.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `year` had a puzzle on `day`, returns [`None`] otherwise.
+    #[must_use]
+    pub const fn try_new(year: Year, day: Day) -> Option<Self> {
+        if day.into_inner() > year.last_day() {
+            return None;
+        }
+
+        Some(Self { year, day })
+    }
+
+    #[must_use]
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(self) -> Day {
+        self.day
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError {})?;
+
+        let year = year.parse().map_err(|_| PuzzleIdFromStrError {})?;
+        let day = day.parse().map_err(|_| PuzzleIdFromStrError {})?;
+
+        Self::try_new(year, day).ok_or(PuzzleIdFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError {}
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle like `2023-17`, with a day that year had a puzzle on")
+    }
+}
+
+/// Creates a [`PuzzleId`] value from a year and a day.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:literal, $day:literal) => {{
+        $crate::shared::puzzle::PuzzleId::try_new($crate::year!($year), $crate::day!($day)).expect(
+            concat!("there was no puzzle on day `", $day, "` of `", $year, "`"),
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::puzzle::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn display() {
+        assert_eq!(puzzle!(2023, 17).to_string(), "2023-17");
+        assert_eq!(puzzle!(2015, 1).to_string(), "2015-01");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("2023-17".parse::<PuzzleId>().ok(), Some(puzzle!(2023, 17)));
+        assert_eq!("2025-12".parse::<PuzzleId>().ok(), Some(puzzle!(2025, 12)));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for invalid in ["2023", "2023-26", "2025-13", "2014-01", "2023-xx", "17"] {
+            assert!(invalid.parse::<PuzzleId>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn order() {
+        assert!(puzzle!(2022, 25) < puzzle!(2023, 1));
+        assert!(PuzzleId::try_new(year!(2025), day!(13)).is_none());
+    }
+}
@@ -0,0 +1,522 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, io};
+
+use serde::Serialize;
+
+use super::PartSolution;
+use super::input::InputSource;
+use super::puzzle::PuzzleId;
+use super::timing::{Stats, to_nanos};
+
+/// The results of a run of `aoc`, as written by `--json` and `--junit`.
+///
+/// The field names are part of the output format, dashboards rely on them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Results {
+    /// The git revision that was run, with a `-dirty` suffix for uncommitted changes.
+    pub revision: Option<String>,
+    /// When the run started, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub puzzles: Vec<PuzzleResult>,
+}
+
+/// The results of a single puzzle, on a single input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PuzzleResult {
+    /// The puzzle, like `2023-17`.
+    pub puzzle: String,
+    pub year: u16,
+    pub day: u8,
+    /// Where the input came from: `inputs` for the checked-in inputs, the name of a profile, a
+    /// path or `stdin`.
+    pub input: String,
+    pub read_ns: u64,
+    /// How long parsing took, or [`None`] when the input couldn't be read or parsed.
+    pub parse: Option<Timings>,
+    pub parts: Vec<PartResult>,
+}
+
+/// The outcome of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The answer matches the known answer.
+    Passed,
+    /// The answer differs from the known answer.
+    Failed,
+    /// We don't know the answer yet, or a human has to read it, so there is nothing to compare
+    /// against.
+    Unverified,
+    /// We don't have the input, so the part didn't run.
+    Skipped,
+    /// The input couldn't be read or parsed, or the part returned an error.
+    Error,
+}
+
+/// The results of a single part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    /// Why the part has no answer, when its status is [`Status::Error`] or [`Status::Skipped`].
+    pub error: Option<String>,
+    pub timings: Option<Timings>,
+}
+
+/// The statistics of a step over every run of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub std_dev_ns: u64,
+}
+
+impl Timings {
+    #[must_use]
+    pub fn new(runs: u32, stats: &Stats) -> Self {
+        Self {
+            runs,
+            min_ns: to_nanos(stats.min),
+            median_ns: to_nanos(stats.median),
+            mean_ns: to_nanos(stats.mean),
+            std_dev_ns: to_nanos(stats.std_dev),
+        }
+    }
+}
+
+impl PuzzleResult {
+    /// A puzzle before any of its parts ran.
+    #[must_use]
+    pub fn new(puzzle: PuzzleId, source: &InputSource, read: Duration) -> Self {
+        Self {
+            puzzle: puzzle.to_string(),
+            year: puzzle.year().into_inner(),
+            day: puzzle.day().into_inner(),
+            input: source.to_string(),
+            read_ns: to_nanos(read),
+            parse: None,
+            parts: vec![],
+        }
+    }
+
+    /// Marks both parts as errors, for an input that couldn't be read or parsed.
+    #[must_use]
+    pub fn with_error(mut self, error: &str) -> Self {
+        self.parts = (1..=2).map(|part| PartResult::error(part, error)).collect();
+
+        self
+    }
+
+    /// Marks both parts as skipped, for an input we don't have.
+    #[must_use]
+    pub fn skipped(mut self, reason: &str) -> Self {
+        self.parts = (1..=2)
+            .map(|part| PartResult {
+                status: Status::Skipped,
+                ..PartResult::error(part, reason)
+            })
+            .collect();
+
+        self
+    }
+
+    /// The median time spent on the puzzle, from reading the input to solving both parts.
+    #[must_use]
+    pub fn total_ns(&self) -> u64 {
+        self.parts
+            .iter()
+            .filter_map(|part| part.timings)
+            .chain(self.parse)
+            .fold(self.read_ns, |total, timings| {
+                total.saturating_add(timings.median_ns)
+            })
+    }
+}
+
+impl PartResult {
+    /// A part that solved, compared with the `expected` answer when we know it. An expected
+    /// [`PartSolution::Manual`] answer leaves the part unverified.
+    #[must_use]
+    pub fn solved(
+        part: u8,
+        answer: &PartSolution,
+        expected: &PartSolution,
+        timings: Timings,
+    ) -> Self {
+        let status = if !expected.has_solution() || matches!(*expected, PartSolution::Manual(_)) {
+            Status::Unverified
+        } else if expected == answer {
+            Status::Passed
+        } else {
+            Status::Failed
+        };
+
+        Self {
+            part,
+            status,
+            answer: Some(answer.to_string()),
+            expected: expected.has_solution().then(|| expected.to_string()),
+            error: None,
+            timings: Some(timings),
+        }
+    }
+
+    /// A part that has no answer, because of `error`.
+    #[must_use]
+    pub fn error(part: u8, error: &str) -> Self {
+        Self {
+            part,
+            status: Status::Error,
+            answer: None,
+            expected: None,
+            error: Some(error.to_owned()),
+            timings: None,
+        }
+    }
+}
+
+impl Results {
+    /// The results as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Never in practice, every field serializes.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The results as `JUnit` XML: a test suite per puzzle and input, and a test case per part.
+    ///
+    /// Unverified parts pass, with their answer in `system-out`.
+    #[must_use]
+    pub fn to_junit(&self) -> String {
+        Junit(self).to_string()
+    }
+
+    /// Writes the results to `path` as JSON.
+    ///
+    /// # Errors
+    /// When the file cannot be written.
+    pub fn write_json(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_json()?)
+    }
+
+    /// Writes the results to `path` as `JUnit` XML.
+    ///
+    /// # Errors
+    /// When the file cannot be written.
+    pub fn write_junit(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_junit())
+    }
+}
+
+/// Writes [`Results`] as `JUnit` XML.
+struct Junit<'a>(&'a Results);
+
+impl Display for Junit<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let results = self.0;
+
+        f.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+
+        let parts = || results.puzzles.iter().flat_map(|puzzle| &puzzle.parts);
+        let count = |status| parts().filter(|part| part.status == status).count();
+
+        writeln!(
+            f,
+            "<testsuites name=\"aoc\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" \
+             time=\"{}\">",
+            parts().count(),
+            count(Status::Failed),
+            count(Status::Error),
+            count(Status::Skipped),
+            seconds(results.puzzles.iter().map(PuzzleResult::total_ns).sum())
+        )?;
+
+        for puzzle in &results.puzzles {
+            let name = escape(&format!("{} ({})", puzzle.puzzle, puzzle.input));
+            let count = |status| {
+                puzzle
+                    .parts
+                    .iter()
+                    .filter(|part| part.status == status)
+                    .count()
+            };
+
+            writeln!(
+                f,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" \
+                 time=\"{}\">",
+                name,
+                puzzle.parts.len(),
+                count(Status::Failed),
+                count(Status::Error),
+                count(Status::Skipped),
+                seconds(puzzle.total_ns())
+            )?;
+
+            for part in &puzzle.parts {
+                let time = part.timings.map_or(0, |timings| timings.median_ns);
+
+                writeln!(
+                    f,
+                    "    <testcase classname=\"{}\" name=\"part {}\" time=\"{}\">",
+                    name,
+                    part.part,
+                    seconds(time)
+                )?;
+
+                let answer = part.answer.as_deref().unwrap_or_default();
+
+                match part.status {
+                    Status::Passed | Status::Unverified => {},
+                    Status::Failed => {
+                        writeln!(
+                            f,
+                            "      <failure message=\"expected {}, got {}\"/>",
+                            escape(part.expected.as_deref().unwrap_or_default()),
+                            escape(answer)
+                        )?;
+                    },
+                    Status::Error => {
+                        writeln!(
+                            f,
+                            "      <error message=\"{}\"/>",
+                            escape(part.error.as_deref().unwrap_or_default())
+                        )?;
+                    },
+                    Status::Skipped => {
+                        writeln!(
+                            f,
+                            "      <skipped message=\"{}\"/>",
+                            escape(part.error.as_deref().unwrap_or_default())
+                        )?;
+                    },
+                }
+
+                if part.answer.is_some() {
+                    writeln!(f, "      <system-out>{}</system-out>", escape(answer))?;
+                }
+
+                f.write_str("    </testcase>\n")?;
+            }
+
+            f.write_str("  </testsuite>\n")?;
+        }
+
+        f.write_str("</testsuites>\n")
+    }
+}
+
+/// Nanoseconds as the fractional seconds `JUnit` expects.
+fn seconds(nanos: u64) -> String {
+    format!("{:.6}", Duration::from_nanos(nanos).as_secs_f64())
+}
+
+/// Escapes `text` for use in an XML attribute, or as text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{PartResult, PuzzleResult, Results, Status, Timings};
+    use crate::shared::PartSolution;
+    use crate::shared::input::InputSource;
+    use crate::shared::timing::Stats;
+
+    fn timings(micros: u64) -> Timings {
+        let duration = Duration::from_micros(micros);
+
+        Timings::new(
+            1,
+            &Stats {
+                min: duration,
+                median: duration,
+                mean: duration,
+                std_dev: Duration::ZERO,
+            },
+        )
+    }
+
+    fn results() -> Results {
+        let mut solved = PuzzleResult::new(
+            "2023-06".parse().unwrap(),
+            &InputSource::CheckedIn,
+            Duration::from_micros(5),
+        );
+
+        solved.parse = Some(timings(10));
+        solved.parts = vec![
+            PartResult::solved(
+                1,
+                &PartSolution::U64(288),
+                &PartSolution::U32(288),
+                timings(100),
+            ),
+            PartResult::solved(
+                2,
+                &PartSolution::from("a<b"),
+                &PartSolution::from("a&b"),
+                timings(200),
+            ),
+        ];
+
+        let unreadable = PuzzleResult::new(
+            "2023-07".parse().unwrap(),
+            &InputSource::Stdin,
+            Duration::ZERO,
+        )
+        .with_error("input: stdin: broken pipe");
+
+        let missing = PuzzleResult::new(
+            "2023-08".parse().unwrap(),
+            &InputSource::Profile(String::from("alice")),
+            Duration::ZERO,
+        )
+        .skipped("no input available");
+
+        Results {
+            revision: Some(String::from("1a2b3c4")),
+            timestamp: 1_701_388_800,
+            puzzles: vec![solved, unreadable, missing],
+        }
+    }
+
+    #[test]
+    fn statuses() {
+        let statuses = results()
+            .puzzles
+            .iter()
+            .flat_map(|puzzle| puzzle.parts.iter().map(|part| part.status))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            [
+                Status::Passed,
+                Status::Failed,
+                Status::Error,
+                Status::Error,
+                Status::Skipped,
+                Status::Skipped
+            ]
+        );
+
+        let unverified =
+            PartResult::solved(1, &PartSolution::U32(1), &PartSolution::None, timings(1));
+
+        assert_eq!(unverified.status, Status::Unverified);
+        assert_eq!(unverified.expected, None);
+
+        let manual = PartResult::solved(
+            2,
+            &PartSolution::from("ABC"),
+            &PartSolution::Manual(vec![]),
+            timings(1),
+        );
+
+        assert_eq!(manual.status, Status::Unverified);
+    }
+
+    #[test]
+    fn total() {
+        assert_eq!(results().puzzles[0].total_ns(), 315_000);
+    }
+
+    #[test]
+    fn json_field_names() {
+        let json: serde_json::Value = serde_json::from_str(&results().to_json().unwrap()).unwrap();
+
+        assert_eq!(json["revision"], "1a2b3c4");
+        assert_eq!(json["timestamp"], 1_701_388_800);
+
+        let puzzle = &json["puzzles"][0];
+
+        assert_eq!(puzzle["puzzle"], "2023-06");
+        assert_eq!(puzzle["year"], 2023);
+        assert_eq!(puzzle["day"], 6);
+        assert_eq!(puzzle["input"], "inputs");
+        assert_eq!(puzzle["read_ns"], 5000);
+        assert_eq!(puzzle["parse"]["median_ns"], 10_000);
+
+        let part = &puzzle["parts"][1];
+
+        assert_eq!(part["part"], 2);
+        assert_eq!(part["status"], "failed");
+        assert_eq!(part["answer"], "a<b");
+        assert_eq!(part["expected"], "a&b");
+        assert_eq!(part["error"], serde_json::Value::Null);
+        assert_eq!(part["timings"]["runs"], 1);
+        assert_eq!(part["timings"]["median_ns"], 200_000);
+
+        let error = &json["puzzles"][1]["parts"][0];
+
+        assert_eq!(error["status"], "error");
+        assert_eq!(error["error"], "input: stdin: broken pipe");
+        assert_eq!(error["timings"], serde_json::Value::Null);
+
+        let skipped = &json["puzzles"][2]["parts"][1];
+
+        assert_eq!(skipped["status"], "skipped");
+        assert_eq!(skipped["error"], "no input available");
+    }
+
+    #[test]
+    fn junit() {
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="aoc" tests="6" failures="1" errors="2" skipped="2" time="0.000315">
+  <testsuite name="2023-06 (inputs)" tests="2" failures="1" errors="0" skipped="0" time="0.000315">
+    <testcase classname="2023-06 (inputs)" name="part 1" time="0.000100">
+      <system-out>288</system-out>
+    </testcase>
+    <testcase classname="2023-06 (inputs)" name="part 2" time="0.000200">
+      <failure message="expected a&amp;b, got a&lt;b"/>
+      <system-out>a&lt;b</system-out>
+    </testcase>
+  </testsuite>
+  <testsuite name="2023-07 (stdin)" tests="2" failures="0" errors="2" skipped="0" time="0.000000">
+    <testcase classname="2023-07 (stdin)" name="part 1" time="0.000000">
+      <error message="input: stdin: broken pipe"/>
+    </testcase>
+    <testcase classname="2023-07 (stdin)" name="part 2" time="0.000000">
+      <error message="input: stdin: broken pipe"/>
+    </testcase>
+  </testsuite>
+  <testsuite name="2023-08 (alice)" tests="2" failures="0" errors="0" skipped="2" time="0.000000">
+    <testcase classname="2023-08 (alice)" name="part 1" time="0.000000">
+      <skipped message="no input available"/>
+    </testcase>
+    <testcase classname="2023-08 (alice)" name="part 2" time="0.000000">
+      <skipped message="no input available"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+
+        assert_eq!(results().to_junit(), expected);
+    }
+}
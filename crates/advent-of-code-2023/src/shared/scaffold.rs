@@ -0,0 +1,558 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::day::Day;
+use super::puzzle::PuzzleId;
+use super::year::Year;
+use crate::days::YEAR;
+
+/// The skeleton a new day starts from. `{{year}}` and `{{day}}` in it are replaced by the puzzle's
+/// year and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Template {
+    /// Two parts without an answer.
+    #[default]
+    Blank,
+    /// A `Grid` of cells, parsed with `TryFrom<char>`.
+    Grid,
+    /// Nodes and the edges between them, parsed from `a: b c` lines.
+    Graph,
+    /// Blocks of lines separated by empty lines.
+    Sections,
+}
+
+impl Template {
+    pub const ALL: [Self; 4] = [Self::Blank, Self::Grid, Self::Graph, Self::Sections];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Blank => "blank",
+            Self::Grid => "grid",
+            Self::Graph => "graph",
+            Self::Sections => "sections",
+        }
+    }
+
+    #[must_use]
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::Blank => include_str!("../template/blank.rs"),
+            Self::Grid => include_str!("../template/grid.rs"),
+            Self::Graph => include_str!("../template/graph.rs"),
+            Self::Sections => include_str!("../template/sections.rs"),
+        }
+    }
+}
+
+impl Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Template {
+    type Err = ScaffoldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name() == s)
+            .ok_or_else(|| ScaffoldError::UnknownTemplate(s.into()))
+    }
+}
+
+/// The widest line rustfmt allows, which the list of registered days is wrapped at.
+const MAX_WIDTH: usize = 100;
+
+/// The name of the module of `day`, e.g. `day07`.
+#[must_use]
+pub fn module_name(day: Day) -> String {
+    format!("day{}", day)
+}
+
+/// Fills in the year and day of `puzzle` in `template`: `{{day}}` is the day number, `{{dd}}` has
+/// two digits.
+#[must_use]
+pub fn render(template: &str, puzzle: PuzzleId) -> String {
+    template
+        .replace("{{year}}", &puzzle.year().to_string())
+        .replace("{{day}}", &puzzle.day().into_inner().to_string())
+        .replace("{{dd}}", &puzzle.day().to_string())
+}
+
+/// Adds `module` to the `pub mod` declarations and to the `days!` registry of `days.rs`, both
+/// kept in order.
+///
+/// # Errors
+/// When `module` is already registered, or `days.rs` doesn't look like we expect.
+pub fn register_module(days: &str, module: &str) -> Result<String, ScaffoldError> {
+    let declaration = format!("pub mod {};", module);
+
+    if days.lines().any(|line| line == declaration) {
+        return Err(ScaffoldError::Registered(module.into()));
+    }
+
+    let mut lines = days.lines().map(str::to_owned).collect::<Vec<_>>();
+
+    let declarations = lines
+        .iter()
+        .enumerate()
+        .filter(|&(_, line)| line.starts_with("pub mod day") && line.ends_with(';'))
+        .map(|(index, line)| (index, line.clone()))
+        .collect::<Vec<_>>();
+
+    let index = match declarations
+        .iter()
+        .find(|&&(_, ref line)| *line > declaration)
+    {
+        Some(&(index, _)) => index,
+        None => {
+            declarations
+                .last()
+                .ok_or(ScaffoldError::Malformed("no `pub mod dayNN;` declarations"))?
+                .0
+                + 1
+        },
+    };
+
+    lines.insert(index, declaration);
+
+    let start = lines
+        .iter()
+        .position(|line| line == "days!(")
+        .ok_or(ScaffoldError::Malformed("no `days!(` registry"))?;
+    let end = lines
+        .iter()
+        .skip(start)
+        .position(|line| line == ");")
+        .ok_or(ScaffoldError::Malformed(
+            "the `days!(` registry isn't closed",
+        ))?
+        + start;
+
+    let mut modules = lines[start + 1..end]
+        .iter()
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    modules.push(module.into());
+    modules.sort_unstable();
+
+    lines.splice(start + 1..end, wrap(&modules));
+
+    let mut days = lines.join("\n");
+    days.push('\n');
+
+    Ok(days)
+}
+
+/// Wraps `modules` the way rustfmt lays out the arguments of a macro call.
+fn wrap(modules: &[String]) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::from("   ");
+
+    for module in modules {
+        if line.len() + 1 + module.len() + 1 > MAX_WIDTH {
+            lines.push(line);
+            line = String::from("   ");
+        }
+
+        line.push(' ');
+        line.push_str(module);
+        line.push(',');
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Adds an empty entry for `day` to the answers manifest, in order. Days that have an entry
+/// already are left alone.
+#[must_use]
+pub fn register_answers(answers: &str, day: Day) -> String {
+    let header = format!("[{}]", day);
+
+    if answers.lines().any(|line| line.trim() == header) {
+        return answers.into();
+    }
+
+    let entry = [
+        header.clone(),
+        String::from("part_1 = \"None\""),
+        String::from("part_2 = \"None\""),
+    ];
+
+    let mut lines = answers.lines().map(str::to_owned).collect::<Vec<_>>();
+
+    // sections are `[NN]`, so the headers sort like the days they stand for
+    if let Some(index) = lines
+        .iter()
+        .position(|line| line.starts_with('[') && *line > header)
+    {
+        lines.splice(index..index, entry.into_iter().chain([String::new()]));
+    } else {
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        lines.extend(entry);
+    }
+
+    let mut answers = lines.join("\n");
+    answers.push('\n');
+    answers
+}
+
+/// The files a scaffold created and updated.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Scaffolds `puzzle` in the crate at `crate_dir`.
+///
+/// Renders `template` into `src/days/dayNN.rs`, registers it in `src/days.rs` and in the
+/// answers manifest, and creates empty example and input stubs when they don't exist yet.
+///
+/// # Errors
+/// When the solution exists already, `puzzle` isn't of [`YEAR`], or a file cannot be read or
+/// written.
+pub fn scaffold(
+    crate_dir: &Path,
+    puzzle: PuzzleId,
+    template: Template,
+) -> Result<Scaffolded, ScaffoldError> {
+    if puzzle.year() != YEAR {
+        return Err(ScaffoldError::OtherYear(puzzle.year()));
+    }
+
+    let module = module_name(puzzle.day());
+    let solution = crate_dir
+        .join("src")
+        .join("days")
+        .join(format!("{}.rs", module));
+
+    if solution.exists() {
+        return Err(ScaffoldError::Exists(solution));
+    }
+
+    let registry = crate_dir.join("src").join("days.rs");
+    let days = register_module(&fs::read_to_string(&registry)?, &module)?;
+
+    let data = crate_dir.join("data").join(puzzle.year().to_string());
+    let manifest = data.join(super::answers::ANSWERS_FILE);
+    let answers = match fs::read_to_string(&manifest) {
+        Ok(answers) => answers,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut scaffolded = Scaffolded::default();
+
+    // `create_new` makes sure we never overwrite a solution that appeared since we checked
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&solution)
+        .map_err(|error| {
+            if error.kind() == io::ErrorKind::AlreadyExists {
+                ScaffoldError::Exists(solution.clone())
+            } else {
+                error.into()
+            }
+        })?
+        .write_all(render(template.source(), puzzle).as_bytes())?;
+    scaffolded.created.push(solution);
+
+    fs::write(&registry, days)?;
+    scaffolded.updated.push(registry);
+
+    let registered = register_answers(&answers, puzzle.day());
+
+    if registered != answers {
+        fs::create_dir_all(&data)?;
+        fs::write(&manifest, registered)?;
+        scaffolded.updated.push(manifest);
+    }
+
+    for folder in ["examples", "inputs"] {
+        let stub = data.join(folder).join(format!("{}.txt", puzzle.day()));
+
+        if !stub.exists() {
+            fs::create_dir_all(data.join(folder))?;
+            fs::write(&stub, "")?;
+            scaffolded.created.push(stub);
+        }
+    }
+
+    Ok(scaffolded)
+}
+
+/// An error which can be returned when scaffolding a day.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Registered(String),
+    UnknownTemplate(String),
+    OtherYear(Year),
+    Malformed(&'static str),
+    Io(io::Error),
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Exists(ref path) => {
+                write!(f, "scaffold: {} exists already", path.display())
+            },
+            Self::Registered(ref module) => {
+                write!(f, "scaffold: {} is registered already", module)
+            },
+            Self::UnknownTemplate(ref name) => {
+                let names = Template::ALL.map(Template::name);
+
+                write!(
+                    f,
+                    "scaffold: unknown template `{}`, expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            },
+            Self::OtherYear(year) => {
+                write!(
+                    f,
+                    "scaffold: this crate only has days of {}, not {}",
+                    YEAR, year
+                )
+            },
+            Self::Malformed(reason) => write!(f, "scaffold: days.rs: {}", reason),
+            Self::Io(ref error) => write!(f, "scaffold: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::answers::Answers;
+    use crate::shared::day::Day;
+    use crate::shared::scaffold::{
+        ScaffoldError, Scaffolded, Template, register_answers, register_module, render, scaffold,
+    };
+    use crate::{day, puzzle};
+
+    const DAYS: &str = "\
+use crate::shared::Parts;
+
+pub mod day01;
+pub mod day03;
+
+days!(
+    day01, day03,
+);
+";
+
+    #[test]
+    fn render_fills_in_the_puzzle() {
+        assert_eq!(
+            render(
+                "//! <https://adventofcode.com/{{year}}/day/{{day}}>",
+                puzzle!(2023, 7)
+            ),
+            "//! <https://adventofcode.com/2023/day/7>"
+        );
+    }
+
+    #[test]
+    fn templates() {
+        for template in Template::ALL {
+            let rendered = render(template.source(), puzzle!(2023, 9));
+
+            assert_eq!(template.name().parse::<Template>().unwrap(), template);
+            assert!(
+                rendered.starts_with("//! The solution of <https://adventofcode.com/2023/day/9>.")
+            );
+            assert!(rendered.contains("crate::solution!();"));
+            assert!(!rendered.contains("{{"));
+        }
+
+        assert_eq!(
+            "maze".parse::<Template>().unwrap_err().to_string(),
+            "scaffold: unknown template `maze`, expected one of blank, grid, graph, sections"
+        );
+    }
+
+    #[test]
+    fn register_module_in_order() {
+        assert_eq!(
+            register_module(DAYS, "day02").unwrap(),
+            "\
+use crate::shared::Parts;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+days!(
+    day01, day02, day03,
+);
+"
+        );
+
+        assert!(
+            register_module(DAYS, "day04")
+                .unwrap()
+                .contains("pub mod day03;\npub mod day04;\n")
+        );
+        assert!(matches!(
+            register_module(DAYS, "day03"),
+            Err(ScaffoldError::Registered(_))
+        ));
+    }
+
+    #[test]
+    fn register_module_wraps_like_rustfmt() {
+        let registered = (1..=23)
+            .map(|day| format!("day{:02}", day))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let days = register_module(
+            &format!("pub mod day01;\n\ndays!(\n    {},\n);\n", registered),
+            "day24",
+        )
+        .unwrap();
+
+        assert!(days.contains(
+            "days!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24,
+);"
+        ));
+    }
+
+    #[test]
+    fn register_answers_in_order() {
+        let answers = "[01]\npart_1 = 1\n\n[03]\npart_1 = 3\n";
+
+        assert_eq!(
+            register_answers(answers, day!(2)),
+            "[01]\npart_1 = 1\n\n[02]\npart_1 = \"None\"\npart_2 = \"None\"\n\n[03]\npart_1 = 3\n"
+        );
+        assert_eq!(
+            register_answers(answers, day!(4)),
+            "[01]\npart_1 = 1\n\n[03]\npart_1 = 3\n\n[04]\npart_1 = \"None\"\npart_2 = \"None\"\n"
+        );
+        assert_eq!(register_answers(answers, day!(3)), answers);
+        assert_eq!(
+            register_answers("", day!(1)),
+            "[01]\npart_1 = \"None\"\npart_2 = \"None\"\n"
+        );
+    }
+
+    #[test]
+    fn scaffold_a_day() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+
+        drop(fs::remove_dir_all(&dir));
+        fs::create_dir_all(dir.join("src/days")).unwrap();
+        fs::create_dir_all(dir.join("data/2023/inputs")).unwrap();
+        fs::write(dir.join("src/days.rs"), DAYS).unwrap();
+        fs::write(dir.join("data/2023/inputs/02.txt"), "kept").unwrap();
+
+        let scaffolded = scaffold(&dir, puzzle!(2023, 2), Template::Grid);
+        let again = scaffold(&dir, puzzle!(2023, 2), Template::Blank);
+        let other_year = scaffold(&dir, puzzle!(2022, 2), Template::Blank);
+
+        let solution = fs::read_to_string(dir.join("src/days/day02.rs")).unwrap();
+        let answers = fs::read_to_string(dir.join("data/2023/answers.toml")).unwrap();
+        let input = fs::read_to_string(dir.join("data/2023/inputs/02.txt")).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            scaffolded.unwrap(),
+            Scaffolded {
+                created: vec![
+                    dir.join("src/days/day02.rs"),
+                    dir.join("data/2023/examples/02.txt")
+                ],
+                updated: vec![dir.join("src/days.rs"), dir.join("data/2023/answers.toml")],
+            }
+        );
+        assert!(
+            matches!(again, Err(ScaffoldError::Exists(ref path)) if path == &dir.join("src/days/day02.rs"))
+        );
+        assert!(matches!(other_year, Err(ScaffoldError::OtherYear(_))));
+        assert!(solution.contains("<https://adventofcode.com/2023/day/2>"));
+        assert!(solution.contains("impl TryFrom<char> for Cell"));
+        assert_eq!(answers, "[02]\npart_1 = \"None\"\npart_2 = \"None\"\n");
+        assert_eq!(input, "kept");
+    }
+
+    #[test]
+    fn scaffolded_days_are_listed() {
+        for template in Template::ALL {
+            let dir = env::temp_dir().join(format!(
+                "aoc-scaffold-{}-{}",
+                process::id(),
+                template.name()
+            ));
+
+            drop(fs::remove_dir_all(&dir));
+            fs::create_dir_all(dir.join("src/days")).unwrap();
+            fs::create_dir_all(dir.join("data/2023")).unwrap();
+            fs::write(dir.join("src/days.rs"), DAYS).unwrap();
+            fs::write(
+                dir.join("data/2023/answers.toml"),
+                "[01]\npart_1 = 1\npart_2 = 2\n\n[03]\npart_1 = 3\npart_2 = 4\n",
+            )
+            .unwrap();
+
+            let scaffolded = scaffold(&dir, puzzle!(2023, 24), template);
+
+            let days = fs::read_to_string(dir.join("src/days.rs")).unwrap();
+            let answers = fs::read_to_string(dir.join("data/2023/answers.toml")).unwrap();
+
+            fs::remove_dir_all(&dir).unwrap();
+
+            scaffolded.unwrap();
+
+            let registered = days
+                .lines()
+                .filter_map(|line| line.strip_prefix("pub mod day")?.strip_suffix(';'))
+                .map(|day| day.parse::<Day>().unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(registered, vec![day!(1), day!(3), day!(24)]);
+            assert_eq!(
+                answers.parse::<Answers>().unwrap().unlisted(registered),
+                Vec::<Day>::new()
+            );
+        }
+    }
+}
@@ -1,59 +1,293 @@
-use std::path::Path;
-use std::{env, fs};
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
 
-use super::day::Day;
+use super::puzzle::PuzzleId;
 
-fn read_file_base(filepath: impl AsRef<Path>) -> String {
-    let f = fs::read_to_string(&filepath);
+/// The environment variable that points to the `data` folder, overriding the defaults.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
 
-    f.unwrap_or_else(|error| {
-        panic!(
-            "Error reading file \"{}\": {error:?}",
-            filepath.as_ref().to_str().unwrap()
-        )
-    })
+/// The `data` folders we look in, in order of preference.
+///
+/// These are `$AOC_DATA_DIR`, then the `data` folder of this crate, then the `data` folder of the
+/// current working directory.
+#[must_use]
+pub fn data_roots() -> Vec<PathBuf> {
+    data_roots_from(env::var_os(DATA_DIR_ENV), env::current_dir().ok())
+}
+
+fn data_roots_from(data_dir: Option<OsString>, cwd: Option<PathBuf>) -> Vec<PathBuf> {
+    let candidates = [
+        data_dir.filter(|d| !d.is_empty()).map(PathBuf::from),
+        Some(Path::new(env!("CARGO_MANIFEST_DIR")).join("data")),
+        cwd.map(|cwd| cwd.join("data")),
+    ];
+
+    let mut roots = Vec::<PathBuf>::new();
+
+    for candidate in candidates.into_iter().flatten() {
+        if !roots.contains(&candidate) {
+            roots.push(candidate);
+        }
+    }
+
+    roots
+}
+
+/// An error which can be returned when none of the [`data_roots`] has the requested file.
+#[derive(Debug)]
+pub struct ReadFileError {
+    pub attempts: Vec<(PathBuf, io::Error)>,
+}
+
+impl ReadFileError {
+    /// Whether the file exists in none of the data roots, rather than failing to read.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.attempts
+            .iter()
+            .all(|&(_, ref error)| error.kind() == io::ErrorKind::NotFound)
+    }
+}
+
+impl Error for ReadFileError {}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "couldn't read the file, tried:")?;
+
+        for &(ref path, ref error) in &self.attempts {
+            write!(f, "\n  {}: {}", path.display(), error)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn try_read_from(roots: &[PathBuf], relative: &Path) -> Result<String, ReadFileError> {
+    let mut attempts = vec![];
+
+    for root in roots {
+        let path = root.join(relative);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => return Ok(contents),
+            Err(error) => attempts.push((path, error)),
+        }
+    }
+
+    Err(ReadFileError { attempts })
+}
+
+/// Reads `relative` from the first of the [`data_roots`] that has it.
+///
+/// # Errors
+/// When no data root has a readable file, listing every path we tried.
+pub fn try_read_data_file<P: AsRef<Path>>(relative: P) -> Result<String, ReadFileError> {
+    try_read_from(&data_roots(), relative.as_ref())
+}
+
+/// Something [`normalize`] changed about an input, or found suspicious about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The input started with a UTF-8 byte order mark, which we removed.
+    ByteOrderMark,
+    /// This many lines ended in `\r\n`, which now end in `\n`.
+    CrLf { lines: usize },
+    /// The last line didn't end in a newline, so we added one.
+    MissingTrailingNewline,
+    /// The input is empty, or only whitespace.
+    Empty,
+    /// This many lines end in whitespace. `first_line` starts at 1.
+    TrailingWhitespace { lines: usize, first_line: usize },
+}
+
+impl Diagnostic {
+    /// Whether [`normalize`] fixed this, rather than only warning about it.
+    #[must_use]
+    pub fn is_fixed(self) -> bool {
+        match self {
+            Self::ByteOrderMark | Self::CrLf { .. } | Self::MissingTrailingNewline => true,
+            Self::Empty | Self::TrailingWhitespace { .. } => false,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::ByteOrderMark => write!(f, "removed the byte order mark"),
+            Self::CrLf { lines } => write!(f, "converted {} CRLF line endings to LF", lines),
+            Self::MissingTrailingNewline => write!(f, "added the missing newline at the end"),
+            Self::Empty => write!(f, "the input is empty"),
+            Self::TrailingWhitespace { lines, first_line } => write!(
+                f,
+                "{} lines end in whitespace, the first is line {}",
+                lines, first_line
+            ),
+        }
+    }
+}
+
+/// An input as the solutions expect it, and what it took to get there.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Normalized {
+    pub input: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Normalizes an input to what the solutions expect: no byte order mark, LF line endings, and a
+/// newline at the end.
+///
+/// Solutions split on `\n` and on empty lines, so a copy-pasted or Windows-edited input would
+/// otherwise give wrong answers rather than errors. Inputs that are empty, or have trailing
+/// whitespace, are left alone but reported.
+#[must_use]
+pub fn normalize(raw: String) -> Normalized {
+    let mut diagnostics = vec![];
+
+    let mut input = match raw.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            diagnostics.push(Diagnostic::ByteOrderMark);
+
+            rest.to_owned()
+        },
+        None => raw,
+    };
+
+    let crlf = input.matches("\r\n").count();
+
+    if crlf > 0 {
+        diagnostics.push(Diagnostic::CrLf { lines: crlf });
+
+        input = input.replace("\r\n", "\n");
+    }
+
+    if input.trim().is_empty() {
+        diagnostics.push(Diagnostic::Empty);
+    }
+
+    if !input.is_empty() && !input.ends_with('\n') {
+        diagnostics.push(Diagnostic::MissingTrailingNewline);
+
+        input.push('\n');
+    }
+
+    let mut trailing_whitespace = input
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| line.ends_with(char::is_whitespace))
+        .map(|(line_index, _)| line_index);
+
+    if let Some(first) = trailing_whitespace.next() {
+        diagnostics.push(Diagnostic::TrailingWhitespace {
+            lines: 1 + trailing_whitespace.count(),
+            first_line: first + 1,
+        });
+    }
+
+    Normalized { input, diagnostics }
+}
+
+/// Reads `relative` from the first of the [`data_roots`] that has it, and [`normalize`]s it.
+fn try_read_normalized(relative: &Path) -> Result<Normalized, ReadFileError> {
+    try_read_data_file(relative).map(normalize)
+}
+
+/// Reads `YYYY/folder/DD.txt` from the first of the [`data_roots`] that has it, and [`normalize`]s
+/// it.
+///
+/// # Errors
+/// When no data root has a readable file, listing every path we tried.
+pub fn try_read_file(folder: &str, puzzle: &PuzzleId) -> Result<Normalized, ReadFileError> {
+    try_read_normalized(
+        &Path::new(&puzzle.year().to_string())
+            .join(folder)
+            .join(format!("{}.txt", puzzle.day())),
+    )
+}
+
+/// Like [`try_read_file`], but appends a part suffix. E.g. like `01-2.txt`.
+///
+/// # Errors
+/// When no data root has a readable file, listing every path we tried.
+pub fn try_read_file_part(
+    folder: &str,
+    puzzle: &PuzzleId,
+    part: u8,
+) -> Result<Normalized, ReadFileError> {
+    try_read_normalized(
+        &Path::new(&puzzle.year().to_string())
+            .join(folder)
+            .join(format!("{}-{}.txt", puzzle.day(), part)),
+    )
 }
 
 /// Helper function that reads a text file to a string.
 ///
+/// The input is [`normalize`]d, but what that changed is dropped. Use [`try_read_file`] to see it.
+///
 /// # Panics
 ///
 /// if the file does not exist or cannot be read.
 #[must_use]
-pub fn read_file(folder: &str, day: &Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{}.txt", day));
-
-    read_file_base(filepath)
+pub fn read_file(folder: &str, puzzle: &PuzzleId) -> String {
+    try_read_file(folder, puzzle)
+        .unwrap_or_else(|error| panic!("{}", error))
+        .input
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 ///
+/// The input is [`normalize`]d, like [`read_file`] does.
+///
 /// # Panics
 ///
 /// if the file does not exist or cannot be read.
 #[must_use]
-pub fn read_file_part(folder: &str, day: &Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{}-{}.txt", day, part));
-
-    read_file_base(filepath)
+pub fn read_file_part(folder: &str, puzzle: &PuzzleId, part: u8) -> String {
+    try_read_file_part(folder, puzzle, part)
+        .unwrap_or_else(|error| panic!("{}", error))
+        .input
 }
 
+/// Declares a day's `Solution`.
+///
+/// The puzzle is derived from the file name and the event of the crate, e.g. `day01.rs` becomes
+/// [`PuzzleId`] `2023-01`, which keys its input and its entry in the
+/// [answers manifest](super::answers::ANSWERS_FILE).
+///
+/// `solution!(Parameters)` gives the `Solution` a `parameters` field, for the constants the puzzle
+/// picks for our input but the examples change. The registry and the tests of our input use
+/// `Parameters::default()`, the tests of the examples pass their own.
 #[macro_export]
 macro_rules! solution {
-    () => {
-        $crate::solution!(PartSolution::None, PartSolution::None);
+    ($parameters:ty) => {
+        $crate::solution!(@puzzle);
+
+        #[derive(Default)]
+        pub struct Solution {
+            pub parameters: $parameters,
+        }
+
+        impl Solution {
+            #[must_use]
+            pub fn new(parameters: $parameters) -> Self {
+                Self { parameters }
+            }
+        }
     };
-    ($solution_1:expr) => {
-        $crate::solution!($solution_1, PartSolution::None);
+    () => {
+        $crate::solution!(@puzzle);
+
+        #[derive(Default)]
+        pub struct Solution {}
     };
-    ($solution_1:expr, $solution_2:expr) => {
-        /// The current day.
-        static DAY: std::sync::LazyLock<$crate::shared::day::Day> =
+    (@puzzle) => {
+        /// The current puzzle.
+        pub static PUZZLE: std::sync::LazyLock<$crate::shared::puzzle::PuzzleId> =
             std::sync::LazyLock::new(|| {
                 use std::path::Path;
 
@@ -64,27 +298,134 @@ macro_rules! solution {
                     .to_str()
                     .expect("Invalid str");
 
-                std::str::FromStr::from_str(file_stem).expect("Could not convert input to Day")
+                let day = std::str::FromStr::from_str(file_stem.trim_start_matches("day"))
+                    .expect("Could not convert input to Day");
+
+                $crate::shared::puzzle::PuzzleId::try_new($crate::days::YEAR, day)
+                    .expect("No puzzle on this day")
             });
+    };
+}
 
-        #[expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        fn main() {
-            // use advent_of_code_2023::template::runner::*;
-            let input = $crate::shared::solution::read_file("inputs", &DAY);
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
 
-            let part_1_expected_solution: PartSolution = PartSolution::from($solution_1);
+    use pretty_assertions::assert_eq;
 
-            let s = Solution {};
+    use crate::puzzle;
+    use crate::shared::solution::{
+        Diagnostic, Normalized, data_roots_from, normalize, try_read_data_file, try_read_file,
+        try_read_from,
+    };
 
-            assert_eq!(part_1_expected_solution, s.part_1(&input));
+    #[test]
+    fn data_roots_order() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
 
-            let part_2_expected_solution: PartSolution = PartSolution::from($solution_2);
+        assert_eq!(
+            data_roots_from(Some("/elsewhere".into()), Some("/work".into())),
+            [
+                PathBuf::from("/elsewhere"),
+                manifest.clone(),
+                PathBuf::from("/work/data"),
+            ]
+        );
 
-            assert_eq!(part_2_expected_solution, s.part_2(&input));
-            // run_part(part_one, &input, &DAY, 1);
-            // run_part(part_two, &input, &DAY, 2);
-        }
+        assert_eq!(
+            data_roots_from(Some("".into()), Some(env!("CARGO_MANIFEST_DIR").into())),
+            [manifest]
+        );
+    }
 
-        pub struct Solution {}
-    };
+    #[test]
+    fn read_from_first_root_that_has_it() {
+        let roots = [
+            PathBuf::from("/does/not/exist"),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+        ];
+
+        let contents = try_read_from(&roots, Path::new("2023/examples/06.txt")).unwrap();
+
+        assert_eq!(contents.lines().count(), 2);
+    }
+
+    #[test]
+    fn error_lists_every_attempt() {
+        let roots = [PathBuf::from("/does/not/exist"), PathBuf::from("/nor/this")];
+
+        let error = try_read_from(&roots, Path::new("inputs/01.txt")).unwrap_err();
+
+        assert_eq!(
+            error
+                .attempts
+                .iter()
+                .map(|&(ref path, _)| path.clone())
+                .collect::<Vec<_>>(),
+            [
+                PathBuf::from("/does/not/exist/inputs/01.txt"),
+                PathBuf::from("/nor/this/inputs/01.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_files_normalized_once() {
+        let raw = try_read_data_file("2023/examples/02.txt").unwrap();
+
+        assert_eq!(
+            try_read_file("examples", &puzzle!(2023, 2)).unwrap(),
+            normalize(raw)
+        );
+    }
+
+    #[test]
+    fn normalize_clean_input() {
+        assert_eq!(
+            normalize(String::from("1\n\n2\n")),
+            Normalized {
+                input: String::from("1\n\n2\n"),
+                diagnostics: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_windows_input() {
+        assert_eq!(
+            normalize(String::from("\u{feff}1\r\n\r\n2")),
+            Normalized {
+                input: String::from("1\n\n2\n"),
+                diagnostics: vec![
+                    Diagnostic::ByteOrderMark,
+                    Diagnostic::CrLf { lines: 2 },
+                    Diagnostic::MissingTrailingNewline,
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_warns_about_suspicious_input() {
+        assert_eq!(
+            normalize(String::from("a\nb \nc\td\ne\t\n")).diagnostics,
+            [Diagnostic::TrailingWhitespace {
+                lines: 2,
+                first_line: 2,
+            }]
+        );
+
+        assert_eq!(
+            normalize(String::from(" \n")).diagnostics,
+            [
+                Diagnostic::Empty,
+                Diagnostic::TrailingWhitespace {
+                    lines: 1,
+                    first_line: 1,
+                },
+            ]
+        );
+
+        assert_eq!(normalize(String::new()).diagnostics, [Diagnostic::Empty]);
+    }
 }
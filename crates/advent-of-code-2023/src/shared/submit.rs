@@ -0,0 +1,650 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::{fs, io};
+
+use num_bigint::BigInt;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::from_str as from_json_str;
+use ureq::{Agent, Error as HttpError, Transport};
+
+use super::PartSolution;
+use super::fetch::{DEFAULT_BASE_URL, agent, base_url_from_env, session};
+use super::puzzle::PuzzleId;
+use super::solution::data_roots;
+
+/// How the server judged an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// We submitted too recently, and have to wait this long before trying again.
+    RateLimited(Option<Duration>),
+    /// The part is already solved, or part 1 isn't solved yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the verdict out of the page the server responds with.
+    #[must_use]
+    pub fn from_response(body: &str) -> Option<Self> {
+        static WAIT: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").expect("Invalid regex")
+        });
+
+        if body.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if body.contains("That's not the right answer") {
+            Some(if body.contains("your answer is too high") {
+                Self::TooHigh
+            } else if body.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if body.contains("You gave an answer too recently") {
+            let wait = WAIT.captures(body).map(|captures| {
+                let number = |index| {
+                    captures
+                        .get(index)
+                        .and_then(|m| m.as_str().parse::<u64>().ok())
+                        .unwrap_or(0)
+                };
+
+                Duration::from_secs(number(1) * 60 + number(2))
+            });
+
+            Some(Self::RateLimited(wait))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the verdict tells us something about the answer, and belongs in the [`History`].
+    #[must_use]
+    pub fn is_about_the_answer(&self) -> bool {
+        match *self {
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong => true,
+            Self::RateLimited(_) | Self::WrongLevel => false,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited(Some(wait)) => write!(f, "rate limited, wait {:?}", wait),
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::WrongLevel => write!(f, "not the right level, is it solved already?"),
+        }
+    }
+}
+
+/// An answer we submitted, and how it was judged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+    /// The answer as a [`PartSolution`], so it can be compared with new guesses.
+    #[must_use]
+    pub fn solution(&self) -> PartSolution {
+        if let Ok(answer) = self.answer.parse::<i64>() {
+            PartSolution::I64(answer)
+        } else if let Ok(answer) = self.answer.parse::<u64>() {
+            PartSolution::U64(answer)
+        } else if let Ok(answer) = self.answer.parse::<BigInt>() {
+            PartSolution::BigInt(answer)
+        } else {
+            PartSolution::String(self.answer.clone())
+        }
+    }
+}
+
+/// Why we won't submit a guess.
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    /// The part is solved, with this answer.
+    Solved(PartSolution),
+    /// The guess was submitted before, and was wrong.
+    KnownWrong,
+    /// The guess is at least as high as an answer that was too high.
+    TooHigh(PartSolution),
+    /// The guess is at most as low as an answer that was too low.
+    TooLow(PartSolution),
+    /// The guess isn't a single line, so it cannot be submitted as is.
+    NotSubmittable,
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Solved(ref answer) => write!(f, "already solved with {}", answer),
+            Self::KnownWrong => write!(f, "already known to be wrong"),
+            Self::TooHigh(ref bound) => write!(f, "too high, {} already was", bound),
+            Self::TooLow(ref bound) => write!(f, "too low, {} already was", bound),
+            Self::NotSubmittable => write!(f, "only single line answers can be submitted"),
+        }
+    }
+}
+
+/// The answers submitted for a part, stored as JSON in the data folder.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Where the history of a part lives, e.g. `<dir>/2023/submissions/17-2.json`.
+    #[must_use]
+    pub fn path(dir: &Path, puzzle: PuzzleId, part: u8) -> PathBuf {
+        dir.join(puzzle.year().to_string())
+            .join("submissions")
+            .join(format!("{}-{}.json", puzzle.day(), part))
+    }
+
+    /// Reads the history of a part, which is empty when nothing was submitted yet.
+    ///
+    /// # Errors
+    /// When the file exists, but cannot be read or parsed.
+    pub fn load(dir: &Path, puzzle: PuzzleId, part: u8) -> Result<Self, SubmitError> {
+        match fs::read_to_string(Self::path(dir, puzzle, part)) {
+            Ok(contents) => Ok(from_json_str(&contents)?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Writes the history of a part, creating its folder when needed.
+    ///
+    /// # Errors
+    /// When the folder or the file cannot be written.
+    pub fn save(&self, dir: &Path, puzzle: PuzzleId, part: u8) -> Result<(), SubmitError> {
+        let path = Self::path(dir, puzzle, part);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    /// Checks `guess` against everything we learned from earlier attempts.
+    ///
+    /// # Errors
+    /// When the part is solved, or `guess` is known to be wrong.
+    pub fn check(&self, guess: &PartSolution) -> Result<(), Refusal> {
+        for attempt in &self.attempts {
+            let answer = attempt.solution();
+
+            let refusal = match attempt.verdict {
+                Verdict::Correct => Some(Refusal::Solved(answer)),
+                Verdict::Wrong if *guess == answer => Some(Refusal::KnownWrong),
+                Verdict::TooHigh if *guess >= answer => Some(Refusal::TooHigh(answer)),
+                Verdict::TooLow if *guess <= answer => Some(Refusal::TooLow(answer)),
+                Verdict::Wrong
+                | Verdict::TooHigh
+                | Verdict::TooLow
+                | Verdict::RateLimited(_)
+                | Verdict::WrongLevel => None,
+            };
+
+            if let Some(refusal) = refusal {
+                return Err(refusal);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Submits answers, keeping a [`History`] per part in the data folder.
+pub struct Submitter {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    dir: PathBuf,
+}
+
+impl Submitter {
+    /// A submitter for [`DEFAULT_BASE_URL`] that keeps its history in `dir`.
+    #[must_use]
+    pub fn new<S: Into<String>>(session: S, dir: PathBuf) -> Self {
+        Self {
+            agent: agent(),
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+            dir,
+        }
+    }
+
+    /// A submitter configured like [`Fetcher::from_env`](super::fetch::Fetcher::from_env),
+    /// keeping its history in the preferred data folder.
+    ///
+    /// # Errors
+    /// When there is no session, or no data folder.
+    pub fn from_env() -> Result<Self, SubmitError> {
+        let session = session().ok_or(SubmitError::NoSession)?;
+        let dir = data_roots()
+            .into_iter()
+            .next()
+            .ok_or(SubmitError::NoDataRoot)?;
+
+        let submitter = Self::new(session, dir);
+
+        Ok(match base_url_from_env() {
+            Some(base_url) => submitter.with_base_url(base_url),
+            None => submitter,
+        })
+    }
+
+    #[must_use]
+    pub fn with_base_url<U: Into<String>>(mut self, base_url: U) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// The history of a part.
+    ///
+    /// # Errors
+    /// When the history exists, but cannot be read or parsed.
+    pub fn history(&self, puzzle: PuzzleId, part: u8) -> Result<History, SubmitError> {
+        History::load(&self.dir, puzzle, part)
+    }
+
+    /// Submits `answer` as the answer to `part` of `puzzle`, unless the [`History`] already tells
+    /// us it is wrong.
+    ///
+    /// # Errors
+    /// When the guess is refused, the server cannot be reached or responds with something we
+    /// don't understand, or the history cannot be read or written.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &PartSolution,
+    ) -> Result<Verdict, SubmitError> {
+        if !(1..=2).contains(&part) {
+            return Err(SubmitError::InvalidPart(part));
+        }
+
+        let mut history = self.history(puzzle, part)?;
+
+        history.check(answer).map_err(SubmitError::Refused)?;
+
+        let answer = match *answer {
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::BigInt(_)
+            | PartSolution::String(_) => answer.to_string(),
+            PartSolution::Bool(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Manual(_)
+            | PartSolution::None => {
+                return Err(SubmitError::Refused(Refusal::NotSubmittable));
+            },
+        };
+
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            puzzle.year(),
+            puzzle.day().into_inner()
+        );
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", &answer)])?;
+
+        let mut body = String::new();
+        response.into_reader().read_to_string(&mut body)?;
+
+        let verdict = Verdict::from_response(&body).ok_or(SubmitError::UnexpectedResponse)?;
+
+        if verdict.is_about_the_answer() {
+            history.attempts.push(Attempt {
+                answer,
+                verdict: verdict.clone(),
+            });
+
+            history.save(&self.dir, puzzle, part)?;
+        }
+
+        Ok(verdict)
+    }
+}
+
+/// An error which can be returned when submitting an answer.
+#[derive(Debug)]
+pub enum SubmitError {
+    NoSession,
+    NoDataRoot,
+    InvalidPart(u8),
+    Refused(Refusal),
+    Status(u16),
+    Transport(Box<Transport>),
+    UnexpectedResponse,
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl From<HttpError> for SubmitError {
+    fn from(error: HttpError) -> Self {
+        match error {
+            HttpError::Status(status, _) => Self::Status(status),
+            HttpError::Transport(transport) => Self::Transport(Box::new(transport)),
+        }
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SubmitError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl Error for SubmitError {}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NoSession => write!(f, "submit: no session, see `aoc --help`"),
+            Self::NoDataRoot => write!(f, "submit: no data folder to keep the history in"),
+            Self::InvalidPart(part) => write!(f, "submit: there is no part {}", part),
+            Self::Refused(ref refusal) => write!(f, "submit: refused, {}", refusal),
+            Self::Status(status) => write!(f, "submit: the server responded with {}", status),
+            Self::Transport(ref error) => write!(f, "submit: {}", error),
+            Self::UnexpectedResponse => write!(f, "submit: couldn't make sense of the response"),
+            Self::Io(ref error) => write!(f, "submit: {}", error),
+            Self::Json(ref error) => write!(f, "submit: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use std::{env, fs, process, thread};
+
+    use num_bigint::BigInt;
+    use pretty_assertions::assert_eq;
+
+    use crate::puzzle;
+    use crate::shared::PartSolution;
+    use crate::shared::submit::{Attempt, History, Refusal, SubmitError, Submitter, Verdict};
+
+    /// A stand-in for the Advent of Code server, where the answer to every part is 42.
+    ///
+    /// Returns its base URL, and the `path | body` of every request it received.
+    fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut length = 0;
+
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+
+                    if header.trim().is_empty() {
+                        break;
+                    }
+
+                    if let Some(value) = header.strip_prefix("Content-Length: ") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body = String::from_utf8(body).unwrap();
+
+                let page = match body.rsplit_once("answer=").map(|(_, answer)| answer) {
+                    Some("42") => "<article><p>That's the right answer!</p></article>",
+                    Some("30") => {
+                        "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>"
+                    },
+                    Some(answer) if answer.parse::<i64>().is_ok_and(|a| a > 42) => {
+                        "<article><p>That's not the right answer; your answer is too high.</p></article>"
+                    },
+                    Some(answer) if answer.parse::<i64>().is_ok() => {
+                        "<article><p>That's not the right answer; your answer is too low.</p></article>"
+                    },
+                    Some(_) | None => "<article><p>That's not the right answer.</p></article>",
+                };
+
+                received.lock().unwrap().push(format!(
+                    "{} | {}",
+                    request_line.split(' ').nth(1).unwrap_or_default(),
+                    body
+                ));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    page.len(),
+                    page
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{}", name, process::id()));
+
+        drop(fs::remove_dir_all(&dir));
+
+        dir
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait."
+            ),
+            Some(Verdict::RateLimited(Some(Duration::from_secs(38))))
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn narrows_down_and_remembers() {
+        let (base_url, requests) = serve();
+        let dir = temp_dir("narrow");
+
+        let submitter = Submitter::new("s3cr3t", dir.clone()).with_base_url(base_url);
+        let submit = |answer: PartSolution| submitter.submit(puzzle!(2023, 3), 2, &answer);
+
+        let verdicts = [
+            submit(100_u32.into()).unwrap(),
+            submit(10_usize.into()).unwrap(),
+            submit(30_i64.into()).unwrap(),
+            submit("abc".into()).unwrap(),
+            submit(42_i32.into()).unwrap(),
+        ];
+
+        let refused = [
+            submit(120_u64.into()).unwrap_err(),
+            submit(10_u32.into()).unwrap_err(),
+            submit("abc".into()).unwrap_err(),
+            submit(41_i32.into()).unwrap_err(),
+        ];
+
+        let history = submitter.history(puzzle!(2023, 3), 2).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            verdicts,
+            [
+                Verdict::TooHigh,
+                Verdict::TooLow,
+                Verdict::RateLimited(Some(Duration::from_secs(65))),
+                Verdict::Wrong,
+                Verdict::Correct
+            ]
+        );
+
+        assert_eq!(
+            refused
+                .into_iter()
+                .map(|error| match error {
+                    SubmitError::Refused(refusal) => refusal,
+                    other @ (SubmitError::NoSession
+                    | SubmitError::NoDataRoot
+                    | SubmitError::InvalidPart(_)
+                    | SubmitError::Status(_)
+                    | SubmitError::Transport(_)
+                    | SubmitError::UnexpectedResponse
+                    | SubmitError::Io(_)
+                    | SubmitError::Json(_)) => panic!("{}", other),
+                })
+                .collect::<Vec<_>>(),
+            [
+                Refusal::TooHigh(100_i64.into()),
+                Refusal::TooLow(10_i64.into()),
+                Refusal::KnownWrong,
+                Refusal::Solved(42_i64.into()),
+            ]
+        );
+
+        // the rate limited attempt says nothing about the answer
+        assert_eq!(
+            history
+                .attempts
+                .iter()
+                .map(|attempt| attempt.answer.as_str())
+                .collect::<Vec<_>>(),
+            ["100", "10", "abc", "42"]
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "/2023/day/3/answer | level=2&answer=100",
+                "/2023/day/3/answer | level=2&answer=10",
+                "/2023/day/3/answer | level=2&answer=30",
+                "/2023/day/3/answer | level=2&answer=abc",
+                "/2023/day/3/answer | level=2&answer=42",
+            ]
+        );
+    }
+
+    #[test]
+    fn refuses_without_asking() {
+        let dir = temp_dir("offline");
+
+        History {
+            attempts: vec![Attempt {
+                answer: String::from("50"),
+                verdict: Verdict::TooHigh,
+            }],
+        }
+        .save(&dir, puzzle!(2023, 1), 1)
+        .unwrap();
+
+        // nothing listens here, so reaching the network would be a transport error
+        let submitter = Submitter::new("s3cr3t", dir.clone()).with_base_url("http://127.0.0.1:9");
+
+        let too_high = submitter.submit(puzzle!(2023, 1), 1, &PartSolution::USize(50));
+        let multi_line = submitter.submit(
+            puzzle!(2023, 1),
+            1,
+            &PartSolution::Vec(vec![String::from("#.")]),
+        );
+        let part_3 = submitter.submit(puzzle!(2023, 1), 3, &PartSolution::USize(1));
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            too_high,
+            Err(SubmitError::Refused(Refusal::TooHigh(_)))
+        ));
+        assert!(matches!(
+            multi_line,
+            Err(SubmitError::Refused(Refusal::NotSubmittable))
+        ));
+        assert!(matches!(part_3, Err(SubmitError::InvalidPart(3))));
+    }
+
+    #[test]
+    fn bounds_beyond_u64() {
+        let history = History {
+            attempts: vec![Attempt {
+                answer: (u128::MAX).to_string(),
+                verdict: Verdict::TooHigh,
+            }],
+        };
+
+        assert_eq!(history.check(&PartSolution::U128(u128::MAX - 1)), Ok(()));
+        assert_eq!(
+            history.check(&PartSolution::BigInt(BigInt::from(u128::MAX) + 1_u8)),
+            Err(Refusal::TooHigh(PartSolution::BigInt(u128::MAX.into())))
+        );
+        assert_eq!(
+            history.check(&PartSolution::Bool(true)),
+            Ok(()),
+            "a boolean is never too high"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+/// Runs `f`, and measures how long it took.
+pub fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// `duration` in whole nanoseconds, saturating at [`u64::MAX`].
+#[must_use]
+pub fn to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Summary statistics over a series of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of `samples`, or [`None`] when there are none.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let min = *sorted.first()?;
+
+        let middle = sorted.len() / 2;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let count = u32::try_from(sorted.len()).ok()?;
+
+        let mean = sorted.iter().sum::<Duration>() / count;
+
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / f64::from(count);
+
+        Some(Self {
+            min,
+            median,
+            mean,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::timing::Stats;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            Stats::from_samples(&millis(&[5])),
+            Some(Stats {
+                min: Duration::from_millis(5),
+                median: Duration::from_millis(5),
+                mean: Duration::from_millis(5),
+                std_dev: Duration::ZERO,
+            })
+        );
+    }
+
+    #[test]
+    fn even_number_of_samples() {
+        let stats = Stats::from_samples(&millis(&[9, 2, 4, 4, 5, 5, 7, 4])).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(
+            stats.median,
+            Duration::from_millis(4) + Duration::from_micros(500)
+        );
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.std_dev, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn odd_number_of_samples() {
+        let stats = Stats::from_samples(&millis(&[3, 1, 2])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(2));
+        assert_eq!(stats.mean, Duration::from_millis(2));
+    }
+}
@@ -108,6 +108,9 @@ impl Side {
 
 /// Converts a slice of `&[Option<T>]` to a Binary tree.
 ///
+/// An empty slice, or one starting with [`None`], yields an empty tree. Values that have no parent
+/// to attach to are ignored.
+///
 /// # Examples
 ///
 /// ```
@@ -123,17 +126,15 @@ impl Side {
 /// Panics if cannot borrow `Rc`.
 #[must_use]
 pub fn to_bt<T: Copy>(input: &[Option<T>]) -> Option<Rc<RefCell<Node<T>>>> {
-    if input.is_empty() {
-        return None;
-    }
-
-    let root = tn(input[0].unwrap(), None, None);
+    let root = tn((*input.first()?)?, None, None);
     let mut queue = VecDeque::from_iter([root.clone()]);
 
     let mut side = Side::Left;
 
     for o in input.iter().skip(1) {
-        let node = queue.front().unwrap().as_ref().unwrap();
+        let Some(node) = queue.front().and_then(Option::as_ref) else {
+            break;
+        };
 
         if let Some(&v) = o.as_ref() {
             let new_node = Some(Rc::new(RefCell::new(Node::new(v))));
@@ -262,6 +263,19 @@ mod tests {
         assert_eq!(to_bt(&input), expected);
     }
 
+    #[test]
+    fn bt_without_root() {
+        assert_eq!(to_bt::<i32>(&[]), None);
+        assert_eq!(to_bt(&[None, 1.into()]), None);
+    }
+
+    #[test]
+    fn bt_orphans() {
+        let input = [1.into(), None, None, 4.into()];
+
+        assert_eq!(to_bt(&input), tn(1, None, None));
+    }
+
     #[test]
     fn iter() {
         let input = (1..=15).map(Some).collect::<Vec<_>>();
@@ -1,83 +1,141 @@
 #[macro_export]
-#[expect(clippy::crate_in_macro_def, reason = "We do want the parent one")]
 macro_rules! test_part_1 {
-    ($value:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file;
+    () => {{
+        use $crate::shared::Parts;
+        use $crate::shared::answers::expected;
+        use $crate::shared::solution::read_file;
+
+        use super::super::{PUZZLE, Solution};
 
-        use crate::{DAY, Solution};
+        let input = read_file("inputs", &PUZZLE);
+        let [expected, _] = expected(*PUZZLE);
 
-        if std::env::var("CI").is_err() {
-            assert_eq!($value, (Solution {}).part_1(&read_file("inputs", &DAY)));
-        }
+        assert_eq!(expected, Solution::default().part_1(&input));
     }};
 }
 
 #[macro_export]
-#[expect(clippy::crate_in_macro_def, reason = "We do want the parent one")]
 macro_rules! test_part_2 {
-    ($value:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file;
+    () => {{
+        use $crate::shared::Parts;
+        use $crate::shared::answers::expected;
+        use $crate::shared::solution::read_file;
 
-        use crate::{DAY, Solution};
+        use super::super::{PUZZLE, Solution};
 
-        if std::env::var("CI").is_err() {
-            assert_eq!($value, (Solution {}).part_2(&read_file("inputs", &DAY)));
-        }
+        let input = read_file("inputs", &PUZZLE);
+        let [_, expected] = expected(*PUZZLE);
+
+        assert_eq!(expected, Solution::default().part_2(&input));
     }};
 }
 
 #[macro_export]
-#[expect(clippy::crate_in_macro_def, reason = "We do want the parent one")]
 macro_rules! test_example_part_1 {
+    ($value:literal, $part:literal, $($field:ident: $parameter:expr),+ $(,)?) => {{
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file_part;
+
+        use super::super::{PUZZLE, Parameters, Solution};
+
+        let mut parameters = Parameters::default();
+        $(parameters.$field = $parameter;)+
+
+        let solution = Solution::new(parameters);
+
+        assert_eq!(
+            $value,
+            solution.part_1(&read_file_part("examples", &PUZZLE, $part))
+        );
+    }};
+    ($value:literal, $($field:ident: $parameter:expr),+ $(,)?) => {{
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file;
+
+        use super::super::{PUZZLE, Parameters, Solution};
+
+        let mut parameters = Parameters::default();
+        $(parameters.$field = $parameter;)+
+
+        let solution = Solution::new(parameters);
+
+        assert_eq!($value, solution.part_1(&read_file("examples", &PUZZLE)));
+    }};
     ($value:literal, $part:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file_part;
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file_part;
 
-        use crate::{DAY, Solution};
+        use super::super::{PUZZLE, Solution};
 
         assert_eq!(
             $value,
-            (Solution {}).part_1(&read_file_part("examples", &DAY, $part))
+            Solution::default().part_1(&read_file_part("examples", &PUZZLE, $part))
         );
     }};
     ($value:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file;
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file;
 
-        use crate::{DAY, Solution};
+        use super::super::{PUZZLE, Solution};
 
-        assert_eq!($value, (Solution {}).part_1(&read_file("examples", &DAY)));
+        assert_eq!(
+            $value,
+            Solution::default().part_1(&read_file("examples", &PUZZLE))
+        );
     }};
 }
 
 #[macro_export]
-#[expect(clippy::crate_in_macro_def, reason = "We do want the parent one")]
 macro_rules! test_example_part_2 {
+    ($value:literal, $part:literal, $($field:ident: $parameter:expr),+ $(,)?) => {{
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file_part;
+
+        use super::super::{PUZZLE, Parameters, Solution};
+
+        let mut parameters = Parameters::default();
+        $(parameters.$field = $parameter;)+
+
+        let solution = Solution::new(parameters);
+
+        assert_eq!(
+            $value,
+            solution.part_2(&read_file_part("examples", &PUZZLE, $part))
+        );
+    }};
+    ($value:literal, $($field:ident: $parameter:expr),+ $(,)?) => {{
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file;
+
+        use super::super::{PUZZLE, Parameters, Solution};
+
+        let mut parameters = Parameters::default();
+        $(parameters.$field = $parameter;)+
+
+        let solution = Solution::new(parameters);
+
+        assert_eq!($value, solution.part_2(&read_file("examples", &PUZZLE)));
+    }};
     ($value:literal, $part:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file_part;
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file_part;
 
-        use crate::{DAY, Solution};
+        use super::super::{PUZZLE, Solution};
 
         assert_eq!(
             $value,
-            (Solution {}).part_2(&read_file_part("examples", &DAY, $part))
+            Solution::default().part_2(&read_file_part("examples", &PUZZLE, $part))
         );
     }};
     ($value:literal) => {{
-        #![expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        use advent_of_code_2023::shared::Parts;
-        use advent_of_code_2023::shared::solution::read_file;
+        use $crate::shared::Parts;
+        use $crate::shared::solution::read_file;
 
-        use crate::{DAY, Solution};
+        use super::super::{PUZZLE, Solution};
 
-        assert_eq!($value, (Solution {}).part_2(&read_file("examples", &DAY)));
+        assert_eq!(
+            $value,
+            Solution::default().part_2(&read_file("examples", &PUZZLE))
+        );
     }};
 }
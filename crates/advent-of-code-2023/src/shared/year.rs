@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::day::Day;
+
+/// An Advent of Code event (i.e. a year from 2015 onward).
+///
+/// ```
+/// # use advent_of_code_2023::shared::year::Year;
+/// const YEAR: Year = Year::try_new(2023).unwrap();
+/// assert_eq!(YEAR.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The first event.
+    pub const FIRST: Self = Self(2015);
+
+    /// Creates a [`Year`] from the provided value if there was an event that year,
+    /// returns [`None`] otherwise.
+    #[must_use]
+    pub const fn try_new(year: u16) -> Option<Self> {
+        if year < Self::FIRST.0 {
+            return None;
+        }
+
+        Some(Year(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    #[must_use]
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of puzzles in this event. From 2025 onward there are 12 instead of 25.
+    #[must_use]
+    pub const fn last_day(self) -> u8 {
+        if self.0 >= 2025 { 12 } else { 25 }
+    }
+
+    /// Checks whether this event has a puzzle on `day`.
+    #[must_use]
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError {})?;
+        Self::try_new(year).ok_or(YearFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub struct YearFromStrError {}
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year from 2015 onward")
+    }
+}
+
+/// Creates a [`Year`] value in a `const` context.
+#[macro_export]
+macro_rules! year {
+    ($year:literal) => {{
+        $crate::shared::year::Year::try_new($year).expect(concat!(
+            "invalid year `",
+            $year,
+            "`, expecting a value from 2015 onward"
+        ))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::day;
+    use crate::shared::year::Year;
+
+    #[test]
+    fn parse() {
+        assert_eq!("2023".parse::<Year>().ok(), Year::try_new(2023));
+        "2014".parse::<Year>().unwrap_err();
+        "twenty".parse::<Year>().unwrap_err();
+    }
+
+    #[test]
+    fn days_per_event() {
+        assert!(year!(2024).has_day(day!(25)));
+        assert!(year!(2025).has_day(day!(12)));
+        assert!(!year!(2025).has_day(day!(13)));
+    }
+}
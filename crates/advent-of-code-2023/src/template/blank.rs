@@ -0,0 +1,73 @@
+//! The solution of <https://adventofcode.com/{{year}}/day/{{day}}>.
+
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+impl ParsedParts for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_1!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_1(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_2!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_2(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+}
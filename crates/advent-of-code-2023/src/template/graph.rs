@@ -0,0 +1,147 @@
+//! The solution of <https://adventofcode.com/{{year}}/day/{{day}}>.
+
+use hashbrown::HashMap;
+
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+/// The nodes, and the nodes each of them is connected to. Edges go both ways.
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub edges: HashMap<String, Vec<String>>,
+}
+
+impl Graph {
+    fn connect(&mut self, from: &str, to: &str) {
+        self.edges
+            .entry(from.to_owned())
+            .or_default()
+            .push(to.to_owned());
+        self.edges
+            .entry(to.to_owned())
+            .or_default()
+            .push(from.to_owned());
+    }
+
+    #[must_use]
+    pub fn neighbors(&self, node: &str) -> &[String] {
+        self.edges.get(node).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Parses lines like `a: b c`, which connect `a` to both `b` and `c`.
+///
+/// # Errors
+/// When a line has no `:`.
+pub fn parse_input(input: &str) -> Result<Graph, SolveError> {
+    let mut graph = Graph::default();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let (node, neighbors) = line
+            .split_once(':')
+            .ok_or_else(|| SolveError::parse_at(line_index, 0, "expected `node: neighbors`"))?;
+
+        let node = node.trim();
+
+        graph.edges.entry(node.to_owned()).or_default();
+
+        for neighbor in neighbors.split_whitespace() {
+            graph.connect(node, neighbor);
+        }
+    }
+
+    Ok(graph)
+}
+
+impl ParsedParts for Solution {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_1!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_1(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_2!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_2(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod parse_input {
+        use pretty_assertions::assert_eq;
+
+        use super::super::parse_input;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn edges_go_both_ways() {
+            let graph = parse_input("a: b c\nd:").unwrap();
+
+            assert_eq!(graph.neighbors("a"), ["b", "c"]);
+            assert_eq!(graph.neighbors("c"), ["a"]);
+            assert!(graph.neighbors("x").is_empty());
+        }
+
+        #[test]
+        fn missing_colon() {
+            assert_eq!(
+                parse_input("a: b\nc d").unwrap_err(),
+                SolveError::parse_at(1, 0, "expected `node: neighbors`")
+            );
+        }
+    }
+}
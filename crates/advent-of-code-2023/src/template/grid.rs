@@ -0,0 +1,134 @@
+//! The solution of <https://adventofcode.com/{{year}}/day/{{day}}>.
+
+use crate::shared::error::SolveError;
+use crate::shared::grids::grid::Grid;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Cell {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell::Open),
+            '#' => Ok(Cell::Wall),
+            _ => Err("expected `.` or `#`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            Cell::Open => '.',
+            Cell::Wall => '#',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+/// Parses the map.
+///
+/// # Errors
+/// When the map is empty, its rows differ in length, or it contains an unknown cell.
+pub fn parse_input(input: &str) -> Result<Grid<Cell>, SolveError> {
+    Ok(Grid::parse(input)?)
+}
+
+impl ParsedParts for Solution {
+    type Input = Grid<Cell>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_1!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_1(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_2!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_2(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod parse_input {
+        use pretty_assertions::assert_eq;
+
+        use super::super::parse_input;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn unknown_cell() {
+            assert_eq!(
+                parse_input("..\n.?").unwrap_err(),
+                SolveError::parse_at(1, 1, "invalid cell `?`: expected `.` or `#`")
+            );
+        }
+
+        #[test]
+        fn ragged() {
+            assert_eq!(
+                parse_input("..\n.").unwrap_err(),
+                SolveError::parse_at(1, 1, "expected 2 cells, found 1")
+            );
+        }
+    }
+}
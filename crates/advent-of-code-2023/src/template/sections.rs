@@ -0,0 +1,157 @@
+//! The solution of <https://adventofcode.com/{{year}}/day/{{day}}>.
+
+use crate::shared::error::SolveError;
+use crate::shared::{ParsedParts, PartSolution};
+
+crate::solution!();
+
+/// A block of lines between empty lines.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Section {
+    /// The index of the first line in the input, to report errors at.
+    pub first_line_index: usize,
+    pub lines: Vec<String>,
+}
+
+/// Splits the input on empty lines. Consecutive empty lines don't make empty sections.
+#[must_use]
+pub fn sections(input: &str) -> Vec<Section> {
+    let mut sections = vec![];
+    let mut current = None;
+
+    for (line_index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            sections.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Section {
+                    first_line_index: line_index,
+                    lines: vec![],
+                })
+                .lines
+                .push(line.to_owned());
+        }
+    }
+
+    sections.extend(current);
+    sections
+}
+
+/// Parses the input, which consists of 2 sections.
+///
+/// # Errors
+/// When there are more or less than 2 sections.
+pub fn parse_input(input: &str) -> Result<[Section; 2], SolveError> {
+    let sections = sections(input);
+
+    let line_index = sections
+        .get(2)
+        .map_or(input.lines().count(), |third| third.first_line_index);
+
+    sections.try_into().map_err(|_| {
+        SolveError::parse_at(
+            line_index,
+            0,
+            "expected 2 sections, separated by an empty line",
+        )
+    })
+}
+
+impl ParsedParts for Solution {
+    type Input = [Section; 2];
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> PartSolution {
+        None.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_1!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_1(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::solution::read_file;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        #[cfg_attr(has_input_{{year}}_{{dd}}, ignore = "not solved yet")]
+        #[cfg_attr(not(has_input_{{year}}_{{dd}}), ignore = "no input")]
+        fn outcome() {
+            crate::test_part_2!();
+        }
+
+        #[test]
+        #[ignore = "not solved yet"]
+        fn example() {
+            assert_eq!(
+                PartSolution::None,
+                Solution::default().part_2(&read_file("examples", &PUZZLE))
+            );
+        }
+    }
+
+    mod parse_input {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{Section, parse_input};
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn two_sections() {
+            assert_eq!(
+                parse_input("a\nb\n\n\nc\n").unwrap(),
+                [
+                    Section {
+                        first_line_index: 0,
+                        lines: vec!["a".to_owned(), "b".to_owned()],
+                    },
+                    Section {
+                        first_line_index: 4,
+                        lines: vec!["c".to_owned()],
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn three_sections() {
+            assert_eq!(
+                parse_input("a\n\nb\n\nc").unwrap_err(),
+                SolveError::parse_at(4, 0, "expected 2 sections, separated by an empty line")
+            );
+        }
+    }
+}
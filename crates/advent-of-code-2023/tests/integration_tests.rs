@@ -14,3 +14,43 @@ fn assert_world_ok2() {
     let cls2 = || false;
     assert_eq!(cls1(), cls2());
 }
+
+#[test]
+fn day05_almanac_is_reachable() {
+    use advent_of_code_2023::days::day05::parse::{IndividualSeeds, parse_lines};
+    use advent_of_code_2023::puzzle;
+    use advent_of_code_2023::shared::solution::read_file;
+
+    let input = read_file("examples", &puzzle!(2023, 5));
+
+    let (almanac, _seeds) = parse_lines::<IndividualSeeds>(&input);
+
+    assert_eq!(almanac.all_the_way(79), 82);
+}
+
+#[test]
+fn day17_a_star_is_reachable() {
+    use advent_of_code_2023::days::day17::{Coordinates, a_star, parse_lines};
+    use advent_of_code_2023::puzzle;
+    use advent_of_code_2023::shared::solution::read_file;
+
+    let input = read_file("examples", &puzzle!(2023, 17));
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let map = parse_lines(&lines).unwrap();
+
+    let goal = Coordinates {
+        row_index: map.len() - 1,
+        column_index: map[0].len() - 1,
+    };
+
+    let path = a_star(&map, (0, 0).into(), goal, 0..=3).unwrap();
+
+    let heat_loss = path
+        .iter()
+        .skip(1)
+        .map(|c| map[c.row_index][c.column_index].value)
+        .sum::<u32>();
+
+    assert_eq!(heat_loss, 102);
+}